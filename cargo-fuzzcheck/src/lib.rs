@@ -8,6 +8,15 @@ use std::process;
 use std::process::{Command, Stdio};
 const TARGET: &str = env!("TARGET");
 const BUILD_FOLDER: &str = "target/fuzzcheck";
+/// The flags instrumenting the comparisons of the fuzzed crate with the hooks of `SanitizerCoverage`
+/// (see `--trace-compares`). `SanitizerCoverage` needs a form of code coverage to be enabled as well,
+/// the cheapest one being a boolean flag per function.
+const TRACE_COMPARES_RUSTFLAGS: [&str; 4] = [
+    "-Cpasses=sancov-module",
+    "-Cllvm-args=-sanitizer-coverage-level=1",
+    "-Cllvm-args=-sanitizer-coverage-inline-bool-flag",
+    "-Cllvm-args=-sanitizer-coverage-trace-compares",
+];
 
 pub enum CompiledTarget {
     Lib,
@@ -32,6 +41,11 @@ pub fn launch_executable(
     cargo_args: &[String],
     stdio: impl Fn() -> Stdio,
 ) -> std::io::Result<process::Child> {
+    let trace_compares_rustflags: &[&str] = if args.trace_compares {
+        &TRACE_COMPARES_RUSTFLAGS
+    } else {
+        &[]
+    };
     let args = string_from_args(args);
     let child = if let Some(name_package) = &name_package {
         let compiled = Command::new("cargo")
@@ -50,6 +64,7 @@ pub fn launch_executable(
             .args(["--cfg", "test"])
             .args(["-Zinstrument-coverage=except-unused-functions", "-Zno-profiler-runtime"])
             .args(["-Coverflow-checks=yes", "-Cforce-frame-pointers", "-g"])
+            .args(trace_compares_rustflags)
             .stdout(stdio())
             .stderr(stdio())
             .spawn()?
@@ -97,12 +112,14 @@ pub fn launch_executable(
                 .spawn()?
         }
     } else {
+        let mut rustflags = "-Zinstrument-coverage=except-unused-functions -Zno-profiler-runtime -Ccodegen-units=1 -Coverflow-checks=yes -Cforce-frame-pointers -g".to_owned();
+        for flag in trace_compares_rustflags {
+            rustflags.push(' ');
+            rustflags.push_str(flag);
+        }
         Command::new("cargo")
             .env("FUZZCHECK_ARGS", args)
-            .env(
-                "RUSTFLAGS",
-                "-Zinstrument-coverage=except-unused-functions -Zno-profiler-runtime -Ccodegen-units=1 -Coverflow-checks=yes -Cforce-frame-pointers -g",
-            )
+            .env("RUSTFLAGS", rustflags)
             .arg("test")
             .args(compiled_target.to_args())
             .args(cargo_args)
@@ -212,6 +229,30 @@ pub fn string_from_args(args: &Arguments) -> String {
             s.push(' ');
             Some(input_file.clone())
         }
+        FuzzerCommand::MinifyCorpus => {
+            s.push_str("--command ");
+            s.push_str(COMMAND_MINIFY_CORPUS);
+            s.push(' ');
+            None
+        }
+        FuzzerCommand::MergeCorpora { corpora } => {
+            s.push_str("--command ");
+            s.push_str(COMMAND_MERGE);
+            s.push(' ');
+            for corpus in corpora {
+                s.push_str(&format!("--{} {} ", IN_CORPUS_FLAG, corpus.display()));
+            }
+            None
+        }
+        FuzzerCommand::Replay { folders } => {
+            s.push_str("--command ");
+            s.push_str(COMMAND_REPLAY);
+            s.push(' ');
+            for folder in folders {
+                s.push_str(&format!("--{} {} ", IN_CORPUS_FLAG, folder.display()));
+            }
+            None
+        }
     };
     if let Some(input_file) = input_file {
         s.push_str(&format!("--{} {} ", INPUT_FILE_FLAG, input_file.display()));
@@ -235,6 +276,9 @@ pub fn string_from_args(args: &Arguments) -> String {
     s.push_str(&corpus_out_args);
     s.push(' ');
 
+    let corpus_sync_interval = args.corpus_sync_interval.map(|x| x.as_secs()).unwrap_or(0);
+    s.push_str(&format!("--{} {} ", CORPUS_SYNC_INTERVAL_FLAG, corpus_sync_interval));
+
     let artifacts_args = args
         .artifacts_folder
         .as_ref()
@@ -251,11 +295,48 @@ pub fn string_from_args(args: &Arguments) -> String {
     s.push_str(&stats_args);
     s.push(' ');
 
+    if let Some(resume) = &args.resume {
+        s.push_str(&format!("--{} {} ", RESUME_FLAG, resume.display()));
+    }
+
+    match &args.events {
+        Some(EventStream::File(path)) => s.push_str(&format!("--{} {} ", EVENTS_FLAG, path.display())),
+        Some(EventStream::FileDescriptor(fd)) => s.push_str(&format!("--{} {} ", EVENTS_FD_FLAG, fd)),
+        None => {}
+    }
+
     s.push_str(&format!("--{} {} ", MAX_INPUT_CPLX_FLAG, args.max_input_cplx as usize));
     s.push_str(&format!("--{} {} ", MAX_DURATION_FLAG, args.maximum_duration.as_secs()));
     s.push_str(&format!("--{} {} ", MAX_ITERATIONS_FLAG, args.maximum_iterations));
+    if let Some(limit) = args.stop_after_no_progress {
+        s.push_str(&format!("--{} {} ", NO_PROGRESS_FLAG, limit));
+    }
+    if let Some(target) = &args.stop_at_coverage {
+        s.push_str(&format!("--{} {} ", STOP_AT_COVERAGE_FLAG, target));
+    }
+    if let Some(timeout) = args.timeout {
+        s.push_str(&format!("--{} {} ", TIMEOUT_FLAG, timeout.as_millis()));
+    }
     if args.stop_after_first_failure {
         s.push_str(&format!("--{} ", STOP_AFTER_FIRST_FAILURE_FLAG));
     }
+    if args.fork {
+        s.push_str(&format!("--{} ", FORK_FLAG));
+    }
+    if args.trace_compares {
+        s.push_str(&format!("--{} ", TRACE_COMPARES_FLAG));
+    }
+    if args.jobs > 1 {
+        s.push_str(&format!("--{} {} ", JOBS_FLAG, args.jobs));
+    }
+    if let Some(seed) = args.seed {
+        s.push_str(&format!("--{} {} ", SEED_FLAG, seed));
+    }
+    if let Some(backtrace_frames) = args.backtrace_frames {
+        s.push_str(&format!("--{} {} ", BACKTRACE_FRAMES_FLAG, backtrace_frames));
+    }
+    if args.invalid_corpus != InvalidCorpusPolicy::default() {
+        s.push_str(&format!("--{} {} ", INVALID_CORPUS_FLAG, args.invalid_corpus.as_str()));
+    }
     s
 }
@@ -139,6 +139,63 @@ fn main() -> Result<(), Box<dyn Error>> {
                 &process::Stdio::inherit,
             )?;
         }
+        FuzzerCommand::MinifyCorpus => {
+            if args.corpus_in.is_none() && matches.opt_present(NO_IN_CORPUS_FLAG) == false {
+                args.corpus_in = Some(PathBuf::new().join(format!("fuzz/{}/corpus", target_name)));
+            }
+            if args.corpus_out.is_none() && matches.opt_present(NO_OUT_CORPUS_FLAG) == false {
+                args.corpus_out = Some(PathBuf::new().join(format!("fuzz/{}/corpus.minified", target_name)));
+            }
+            if args.artifacts_folder.is_none() && matches.opt_present(NO_ARTIFACTS_FLAG) == false {
+                args.artifacts_folder = Some(PathBuf::new().join(format!("fuzz/{}/artifacts", target_name)));
+            }
+            let exec = launch_executable(
+                target_name,
+                &args,
+                if instrument_only_root { name_exec } else { None },
+                &compiled_target,
+                &cargo_args,
+                process::Stdio::inherit,
+            )?;
+            let output = exec.wait_with_output()?;
+            if !output.status.success() {
+                process::exit(output.status.code().unwrap_or(1));
+            }
+        }
+        FuzzerCommand::MergeCorpora { .. } => {
+            if args.corpus_out.is_none() && matches.opt_present(NO_OUT_CORPUS_FLAG) == false {
+                args.corpus_out = Some(PathBuf::new().join(format!("fuzz/{}/corpus", target_name)));
+            }
+            if args.artifacts_folder.is_none() && matches.opt_present(NO_ARTIFACTS_FLAG) == false {
+                args.artifacts_folder = Some(PathBuf::new().join(format!("fuzz/{}/artifacts", target_name)));
+            }
+            let exec = launch_executable(
+                target_name,
+                &args,
+                if instrument_only_root { name_exec } else { None },
+                &compiled_target,
+                &cargo_args,
+                process::Stdio::inherit,
+            )?;
+            let output = exec.wait_with_output()?;
+            if !output.status.success() {
+                process::exit(output.status.code().unwrap_or(1));
+            }
+        }
+        FuzzerCommand::Replay { .. } => {
+            let exec = launch_executable(
+                target_name,
+                &args,
+                if instrument_only_root { name_exec } else { None },
+                &compiled_target,
+                &cargo_args,
+                process::Stdio::inherit,
+            )?;
+            let output = exec.wait_with_output()?;
+            if !output.status.success() {
+                process::exit(output.status.code().unwrap_or(1));
+            }
+        }
         FuzzerCommand::Read { .. } => {
             let exec = launch_executable(
                 target_name,
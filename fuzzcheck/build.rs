@@ -10,4 +10,8 @@ fn main() {
         .file(file_to_compile)
         .compile("instrumentation_pointers");
     println!("cargo:rerun-if-changed={}", file_to_compile);
+
+    let file_to_compile = "src/comparisons_sensor/trace_compares.c";
+    cc::Build::new().file(file_to_compile).compile("trace_compares");
+    println!("cargo:rerun-if-changed={}", file_to_compile);
 }
@@ -0,0 +1,157 @@
+//! A minimal executor running the futures returned by async test functions.
+//!
+//! The future is polled on the current thread until it completes. There is no
+//! reactor or timer: when the future returns `Poll::Pending`, the thread is parked
+//! until the future is woken, either by itself or by another thread that was given
+//! its waker. If the future returns `Poll::Pending` without having been woken and
+//! without keeping its waker, nothing can ever wake it and it is considered stuck.
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+/// Records that the future was woken and unparks the thread running it
+struct ThreadWaker {
+    thread: Thread,
+    woken: AtomicBool,
+}
+
+impl Wake for ThreadWaker {
+    #[no_coverage]
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+    #[no_coverage]
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::SeqCst);
+        self.thread.unpark();
+    }
+}
+
+/// Run the future to completion on the current thread and return its output, or `None` if it is pending forever.
+#[no_coverage]
+pub(crate) fn block_on<F: Future>(future: F) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let thread_waker = Arc::new(ThreadWaker {
+        thread: thread::current(),
+        woken: AtomicBool::new(false),
+    });
+    let waker = Waker::from(thread_waker.clone());
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        thread_waker.woken.store(false, Ordering::SeqCst);
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return Some(output),
+            Poll::Pending => {
+                // only `thread_waker` and `waker` refer to the waker, so no one else can wake the future
+                let is_stuck = !thread_waker.woken.load(Ordering::SeqCst) && Arc::strong_count(&thread_waker) <= 2;
+                if is_stuck {
+                    return None;
+                }
+                // the future is polled again if the thread is unparked spuriously, which is harmless
+                thread::park();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::thread;
+    use std::time::Duration;
+
+    use super::block_on;
+
+    /// A future that is pending a given number of times, and wakes itself each time
+    struct YieldNow(usize);
+
+    impl Future for YieldNow {
+        type Output = usize;
+        #[no_coverage]
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+            if self.0 == 0 {
+                Poll::Ready(42)
+            } else {
+                self.0 -= 1;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Shared {
+        value: Option<usize>,
+        waker: Option<Waker>,
+    }
+    /// A future that is ready once another thread has set its value, and woken it
+    struct WaitForValue(Arc<Mutex<Shared>>);
+
+    impl Future for WaitForValue {
+        type Output = usize;
+        #[no_coverage]
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+            let mut shared = self.0.lock().unwrap();
+            if let Some(value) = shared.value {
+                Poll::Ready(value)
+            } else {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_block_on_ready_future() {
+        assert_eq!(block_on(async { 42 }), Some(42));
+        assert_eq!(block_on(YieldNow(0)), Some(42));
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_block_on_self_waking_future() {
+        assert_eq!(block_on(YieldNow(10)), Some(42));
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_block_on_future_woken_by_another_thread() {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let handle = thread::spawn({
+            let shared = shared.clone();
+            #[no_coverage]
+            move || {
+                thread::sleep(Duration::from_millis(50));
+                let mut shared = shared.lock().unwrap();
+                shared.value = Some(42);
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            }
+        });
+        assert_eq!(block_on(WaitForValue(shared)), Some(42));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_block_on_stuck_future() {
+        assert_eq!(block_on(std::future::pending::<usize>()), None);
+        // a future that wakes itself a few times before being stuck
+        assert_eq!(
+            block_on(async {
+                YieldNow(3).await;
+                std::future::pending::<usize>().await
+            }),
+            None
+        );
+    }
+}
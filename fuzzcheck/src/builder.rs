@@ -58,23 +58,36 @@ You can also look at the types provided in the [`sensors_and_pools`](crate::sens
 is to use the [`SensorAndPoolBuilder`], although it only offers a couple limited options.
 */
 
+use crate::block_on::block_on;
 use crate::code_coverage_sensor::CodeCoverageSensor;
 use crate::fuzzer::{Fuzzer, FuzzingResult};
+use crate::reporters::TerminalReporter;
 use crate::sensors_and_pools::MaximiseEachCounterPool;
 use crate::sensors_and_pools::MostNDiversePool;
 use crate::sensors_and_pools::SimplestToActivateCounterPool;
 use crate::sensors_and_pools::WrapperSensor;
 use crate::sensors_and_pools::{AndPool, SameObservations};
 use crate::sensors_and_pools::{DifferentObservations, MaximiseObservationPool};
-use crate::traits::{CompatibleWithObservations, Mutator, PoolExt, Sensor, SensorExt, Serializer};
-use crate::{split_string_by_whitespace, DefaultMutator};
+use crate::sensors_and_pools::{NoopSensor, UnitPool};
+use crate::sensors_and_pools::{TestFailure, TEST_FAILURE};
+use crate::traits::{CompatibleWithObservations, Mutator, PoolExt, Reporter, Sensor, SensorExt, Serializer};
+use crate::{split_string_by_whitespace, DefaultMutator, PoolStorageIndex};
 
 #[cfg(feature = "serde_json_serializer")]
 use crate::SerdeSerializer;
 
-use fuzzcheck_common::arg::{options_parser, ArgumentsError, COMMAND_FUZZ, COMMAND_MINIFY_INPUT, INPUT_FILE_FLAG};
-use fuzzcheck_common::arg::{Arguments, FuzzerCommand};
+use fuzzcheck_common::arg::{
+    options_parser, ArgumentsError, COMMAND_FLAG, COMMAND_FUZZ, COMMAND_MINIFY_INPUT, COMMAND_REPLAY, INPUT_FILE_FLAG,
+    IN_CORPUS_FLAG, NO_ARTIFACTS_FLAG, NO_OUT_CORPUS_FLAG, NO_STATS_FLAG,
+};
+use fuzzcheck_common::arg::{
+    seed_from_matches, Arguments, CoverageTarget, EventStream, FuzzerCommand, InvalidCorpusPolicy, NoProgressLimit,
+};
 use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Debug;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::path::Path;
 use std::result::Result;
@@ -96,8 +109,10 @@ pub trait FuzzTestFunction<T, FT: ?Sized, ImplId> {
 pub enum ReturnBool {}
 /// Marker type for a function of type `Fn(&T)`
 pub enum ReturnVoid {}
-/// Marker type for a function of type `Fn(&T) -> Result<V, E>`
+/// Marker type for a function of type `Fn(&T) -> Result<V, E>`, where `E: Debug`
 pub enum ReturnResult {}
+/// Marker type for an async function, whose future outputs a `bool`, `()`, or `Result<V, E>`
+pub enum ReturnFuture {}
 
 impl<T, FT: ?Sized, F> FuzzTestFunction<T, FT, ReturnBool> for F
 where
@@ -131,11 +146,111 @@ impl<T, FT: ?Sized, F, S, E> FuzzTestFunction<T, FT, ReturnResult> for F
 where
     T: Borrow<FT>,
     F: Fn(&FT) -> Result<E, S>,
+    S: Debug,
 {
     type NormalizedFunction = impl Fn(&T) -> bool;
     #[no_coverage]
     fn test_function(self) -> Self::NormalizedFunction {
-        move |x| self(x.borrow()).is_ok()
+        #[no_coverage]
+        move |x| match self(x.borrow()) {
+            Ok(_) => true,
+            Err(error) => {
+                record_test_failure(TestFailure::from_error(&error));
+                false
+            }
+        }
+    }
+}
+
+/// Record the given test failure, such that it is used instead of the generic
+/// failure of a test function returning `false`
+#[no_coverage]
+fn record_test_failure(test_failure: TestFailure) {
+    unsafe {
+        TEST_FAILURE = Some(test_failure);
+    }
+}
+
+/// The output of the future returned by an async test function.
+pub trait AsyncTestOutput {
+    /// Whether the output is a success, as opposed to a test failure
+    fn is_success(&self) -> bool;
+    /// A description of the test failure, if the output is a failure that carries more information than `false`
+    #[no_coverage]
+    fn test_failure(&self) -> Option<TestFailure> {
+        None
+    }
+}
+impl AsyncTestOutput for bool {
+    #[no_coverage]
+    fn is_success(&self) -> bool {
+        *self
+    }
+}
+impl AsyncTestOutput for () {
+    #[no_coverage]
+    fn is_success(&self) -> bool {
+        true
+    }
+}
+impl<V, E: Debug> AsyncTestOutput for Result<V, E> {
+    #[no_coverage]
+    fn is_success(&self) -> bool {
+        self.is_ok()
+    }
+    #[no_coverage]
+    fn test_failure(&self) -> Option<TestFailure> {
+        self.as_ref().err().map(TestFailure::from_error)
+    }
+}
+
+/// An async function whose argument is a reference of lifetime `'a`.
+///
+/// It is implemented for all functions of type `Fn(&'a T) -> impl Future`, including
+/// `async fn`, whose future may borrow the argument.
+pub trait AsyncTestFunction<'a, T: ?Sized + 'a> {
+    type Output: AsyncTestOutput;
+    type Future: Future<Output = Self::Output>;
+    fn call(&self, x: &'a T) -> Self::Future;
+}
+impl<'a, T: ?Sized + 'a, F, Fut> AsyncTestFunction<'a, T> for F
+where
+    F: Fn(&'a T) -> Fut,
+    Fut: Future,
+    Fut::Output: AsyncTestOutput,
+{
+    type Output = Fut::Output;
+    type Future = Fut;
+    #[no_coverage]
+    fn call(&self, x: &'a T) -> Self::Future {
+        self(x)
+    }
+}
+
+impl<T, FT: ?Sized, F> FuzzTestFunction<T, FT, ReturnFuture> for F
+where
+    T: Borrow<FT>,
+    F: for<'a> AsyncTestFunction<'a, FT>,
+{
+    type NormalizedFunction = impl Fn(&T) -> bool;
+    #[no_coverage]
+    fn test_function(self) -> Self::NormalizedFunction {
+        #[no_coverage]
+        move |x| match block_on(self.call(x.borrow())) {
+            Some(output) => {
+                if let Some(test_failure) = output.test_failure() {
+                    record_test_failure(test_failure);
+                }
+                output.is_success()
+            }
+            None => {
+                let display = "the future returned by the test function is pending forever".to_owned();
+                let mut hasher = DefaultHasher::new();
+                display.hash(&mut hasher);
+                record_test_failure(TestFailure::new(display, hasher.finish()));
+                false
+            }
+        }
     }
 }
 
@@ -149,12 +264,16 @@ where
 /// to use the default mutator, serializer, sensor, pool, and arguments, and obtain a [`FuzzerBuilder5`].
 /// This method is only available if the argument of the test function implements [`DefaultMutator`]
 /// and is serializable with serde.
+///
+/// To make the fuzzer run reproducible, use [`self.seed(..)`](FuzzerBuilder1::seed) before
+/// creating the mutator, sensor, and pool. The seed has no effect on a mutator or pool created before it.
 pub struct FuzzerBuilder1<T, F>
 where
     T: ?Sized,
     F: Fn(&T) -> bool + 'static,
 {
     test_function: F,
+    seed: Option<u64>,
     _phantom: PhantomData<*const T>,
 }
 
@@ -169,6 +288,7 @@ where
 {
     test_function: F,
     mutator: M,
+    seed: Option<u64>,
     _phantom: PhantomData<*const V>,
 }
 
@@ -187,6 +307,7 @@ where
     test_function: F,
     mutator: M,
     serializer: Box<dyn Serializer<Value = V>>,
+    seed: Option<u64>,
     _phantom: PhantomData<*const V>,
 }
 
@@ -210,6 +331,7 @@ where
     serializer: Box<dyn Serializer<Value = V>>,
     sensor: Sens,
     pool: P,
+    seed: Option<u64>,
     _phantom: PhantomData<*const V>,
 }
 
@@ -221,11 +343,23 @@ where
 /// * [`self.command(..)`](FuzzerBuilder5::command)
 /// * [`self.in_corpus(..)`](FuzzerBuilder5::in_corpus)
 /// * [`self.out_corpus(..)`](FuzzerBuilder5::out_corpus)
+/// * [`self.corpus_sync_interval(..)`](FuzzerBuilder5::corpus_sync_interval)
 /// * [`self.artifacts_folder(..)`](FuzzerBuilder5::artifacts_folder)
+/// * [`self.resume(..)`](FuzzerBuilder5::resume)
 /// * [`self.maximum_complexity(..)`](FuzzerBuilder5::maximum_complexity)
 /// * [`self.stop_after_iterations(..)`](FuzzerBuilder5::stop_after_iterations)
 /// * [`self.stop_after_duration(..)`](FuzzerBuilder5::stop_after_duration)
+/// * [`self.stop_after_no_progress(..)`](FuzzerBuilder5::stop_after_no_progress)
+/// * [`self.stop_at_coverage(..)`](FuzzerBuilder5::stop_at_coverage)
 /// * [`self.stop_after_first_test_failure(..)`](FuzzerBuilder5::stop_after_first_test_failure)
+/// * [`self.timeout(..)`](FuzzerBuilder5::timeout)
+/// * [`self.fork(..)`](FuzzerBuilder5::fork)
+/// * [`self.jobs(..)`](FuzzerBuilder5::jobs)
+/// * [`self.trace_compares(..)`](FuzzerBuilder5::trace_compares)
+/// * [`self.backtrace_frames(..)`](FuzzerBuilder5::backtrace_frames)
+/// * [`self.invalid_corpus(..)`](FuzzerBuilder5::invalid_corpus)
+/// * [`self.events(..)`](FuzzerBuilder5::events)
+/// * [`self.reporter(..)`](FuzzerBuilder5::reporter)
 pub struct FuzzerBuilder5<F, M, V, Sens, P>
 where
     F: Fn(&V) -> bool + 'static,
@@ -240,6 +374,7 @@ where
     sensor: Sens,
     pool: P,
     pub arguments: Arguments,
+    reporter: Box<dyn Reporter>,
     _phantom: PhantomData<*const V>,
 }
 
@@ -249,11 +384,18 @@ where
     The returned value is a [`FuzzerBuilder1`]. See the [module/crate documentation](crate::builder)
     for a full example of how to build a fuzz test.
 
-    There are currently three kinds of functions that can be passed as arguments:
+    There are currently four kinds of functions that can be passed as arguments:
 
     1. `Fn(&T)` : the fuzzer will only report a failure when the given function crashes
     2. `Fn(&T) -> Bool` : the fuzzer will report a failure when the output is `false`
-    3. `Fn(&T) -> Result<_,_>` : the fuzzer will report a failure when the output is `Err(..)`
+    3. `Fn(&T) -> Result<_, E>` where `E: Debug` : the fuzzer will report a failure when the output is `Err(..)`.
+    The failure is described by the debug representation of the error, and errors of different types or enum
+    variants are considered to be different failures. An error type that does not implement `Debug` must be
+    converted to one that does, for example with `.map_err(|_| "description of the error")`.
+    4. `Fn(&T) -> impl Future`, such as an `async fn` : the future is run to completion on the current
+    thread and its output is interpreted as above. While the future is pending, the thread is parked until
+    the future is woken, by itself or by another thread. There is no runtime to wake it up otherwise, so the
+    fuzzer reports a failure when the future is pending without having been woken and without keeping its waker.
 */
 #[no_coverage]
 pub fn fuzz_test<T, F, TestFunctionKind>(test_function: F) -> FuzzerBuilder1<T::Owned, F::NormalizedFunction>
@@ -262,9 +404,353 @@ where
     T::Owned: Clone,
     F: FuzzTestFunction<T::Owned, T, TestFunctionKind>,
 {
-    FuzzerBuilder1 {
-        test_function: test_function.test_function(),
-        _phantom: PhantomData,
+    FuzzerBuilder1::new(test_function.test_function())
+}
+
+/**
+    Build a fuzz test comparing two implementations of the same function!
+
+    The fuzzer reports a failure when the outputs of `f` and `g` on the same input are different.
+    The failure is described by the debug representation of both outputs.
+
+    It is typically used to compare an optimised implementation with a simpler reference implementation.
+    Use [`fuzz_test_differential_by`] to compare the outputs with a custom equivalence function instead of `==`.
+*/
+#[no_coverage]
+pub fn fuzz_test_differential<T, F, G, O1, O2>(f: F, g: G) -> FuzzerBuilder1<T::Owned, impl Fn(&T::Owned) -> bool>
+where
+    T: ?Sized + ToOwned + 'static,
+    T::Owned: Clone,
+    F: Fn(&T) -> O1 + 'static,
+    G: Fn(&T) -> O2 + 'static,
+    O1: PartialEq<O2> + Debug + 'static,
+    O2: Debug + 'static,
+{
+    fuzz_test_differential_by(
+        f,
+        g,
+        #[no_coverage]
+        |x, y| x == y,
+    )
+}
+
+/**
+    Build a fuzz test comparing two implementations of the same function, using the given equivalence function!
+
+    The fuzzer reports a failure when `equivalent(&f(x), &g(x))` is `false`.
+    The failure is described by the debug representation of both outputs. The differences between outputs of
+    the same enum variants, such as `Ok(..)` and `Err(..)`, are grouped into a single kind of test failure.
+*/
+#[no_coverage]
+pub fn fuzz_test_differential_by<T, F, G, O1, O2, E>(
+    f: F,
+    g: G,
+    equivalent: E,
+) -> FuzzerBuilder1<T::Owned, impl Fn(&T::Owned) -> bool>
+where
+    T: ?Sized + ToOwned + 'static,
+    T::Owned: Clone,
+    F: Fn(&T) -> O1 + 'static,
+    G: Fn(&T) -> O2 + 'static,
+    O1: Debug + 'static,
+    O2: Debug + 'static,
+    E: Fn(&O1, &O2) -> bool + 'static,
+{
+    FuzzerBuilder1::new(
+        #[no_coverage]
+        move |x: &T::Owned| {
+            let x = x.borrow();
+            let (output_f, output_g) = (f(x), g(x));
+            if equivalent(&output_f, &output_g) {
+                true
+            } else {
+                let mut hasher = DefaultHasher::new();
+                std::any::type_name::<F>().hash(&mut hasher);
+                std::any::type_name::<G>().hash(&mut hasher);
+                hash_output_variants(&output_f, &output_g, &mut hasher);
+                record_test_failure(TestFailure {
+                    display: format!(
+                        "the two implementations returned different outputs\nfirst: {:?}\nsecond: {:?}",
+                        output_f, output_g
+                    ),
+                    id: hasher.finish(),
+                    frames: vec![],
+                });
+                false
+            }
+        },
+    )
+}
+
+/// Hash the types of two different outputs and, if they are enums, their variants, so that the
+/// differences between outputs of the same variants are grouped into a single kind of test failure
+#[no_coverage]
+fn hash_output_variants<O1, O2>(first: &O1, second: &O2, hasher: &mut impl Hasher) {
+    std::any::type_name::<O1>().hash(hasher);
+    std::any::type_name::<O2>().hash(hasher);
+    std::mem::discriminant(first).hash(hasher);
+    std::mem::discriminant(second).hash(hasher);
+}
+
+/// A stateful component, or a model of its behaviour, that can be tested by
+/// [`fuzz_test_stateful`] and [`fuzz_test_model_based`].
+///
+/// The state machine is driven by a sequence of operations of type `Op`, which is usually an enum
+/// deriving [`DefaultMutator`](crate::DefaultMutator).
+pub trait StateMachine<Op> {
+    /// The observable result of an operation
+    type Output: Debug;
+
+    /// Apply the operation to the state machine
+    fn apply(&mut self, operation: &Op) -> Self::Output;
+
+    /// Check the postconditions that must hold after each operation.
+    ///
+    /// An error message is reported as a test failure, and the test failures are grouped by message.
+    /// The message should therefore describe the violated postcondition rather than the current state.
+    /// By default, there are no postconditions.
+    #[no_coverage]
+    fn check_postconditions(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/**
+    Build a fuzz test of a stateful component!
+
+    The test cases are sequences of operations. For each sequence, a new system is created by `new_system`,
+    then each operation is [applied](StateMachine::apply) to it, followed by a call to
+    [`check_postconditions`](StateMachine::check_postconditions). The test fails if a postcondition
+    is violated or if an operation panics, and the failure is described by the index of the failing step
+    and its operation.
+
+    With the default mutator of `Vec<Op>`, which is a [`VecMutator`](crate::mutators::vector::VecMutator),
+    `cargo fuzzcheck --command minify` reduces a failing sequence with its
+    [`ordered_simplify`](crate::Mutator::ordered_simplify) method: it removes chunks of operations, from the
+    largest to the smallest, and then simplifies each remaining operation, for as long as the sequence keeps
+    failing. The operations that are irrelevant to the failure are therefore removed.
+
+    ```no_run
+    # #![feature(no_coverage)]
+    use fuzzcheck::{DefaultMutator, StateMachine};
+
+    #[derive(Clone, Debug, DefaultMutator)]
+    enum Op {
+        Push(u8),
+        Pop,
+    }
+
+    struct Stack(Vec<u8>);
+
+    impl StateMachine<Op> for Stack {
+        type Output = Option<u8>;
+        fn apply(&mut self, operation: &Op) -> Option<u8> {
+            match operation {
+                Op::Push(x) => {
+                    self.0.push(*x);
+                    None
+                }
+                Op::Pop => self.0.pop(),
+            }
+        }
+        fn check_postconditions(&self) -> Result<(), String> {
+            if self.0.len() <= 100 {
+                Ok(())
+            } else {
+                Err("the stack is too large".to_owned())
+            }
+        }
+    }
+
+    let builder = fuzzcheck::fuzz_test_stateful(|| Stack(vec![])).default_mutator();
+    ```
+*/
+#[no_coverage]
+pub fn fuzz_test_stateful<Op, S, NewS>(new_system: NewS) -> FuzzerBuilder1<Vec<Op>, impl Fn(&Vec<Op>) -> bool>
+where
+    Op: Clone + Debug + 'static,
+    S: StateMachine<Op>,
+    NewS: Fn() -> S + 'static,
+{
+    fuzz_test::<Vec<Op>, _, ReturnBool>(
+        #[no_coverage]
+        move |operations: &Vec<Op>| {
+            let mut system = new_system();
+            run_operations(
+                operations,
+                #[no_coverage]
+                |operation| {
+                    system.apply(operation);
+                    system.check_postconditions().map_err(postcondition_failure)
+                },
+            )
+        },
+    )
+}
+
+/**
+    Build a fuzz test comparing a stateful component with a model of its behaviour!
+
+    It is similar to [`fuzz_test_stateful`], but each operation is applied to both a new system, created by
+    `new_system`, and a new model, created by `new_model`. After each step, the output of the system must be
+    equal to the output of the model, and the postconditions of the system must hold.
+
+    A difference between the outputs is described by the index of the failing step, its operation, and the
+    two outputs. The differences found on operations of the same variant of `Op`, with outputs of the same
+    enum variants, are grouped into a single kind of test failure.
+*/
+#[no_coverage]
+pub fn fuzz_test_model_based<Op, S, M, NewS, NewM>(
+    new_system: NewS,
+    new_model: NewM,
+) -> FuzzerBuilder1<Vec<Op>, impl Fn(&Vec<Op>) -> bool>
+where
+    Op: Clone + Debug + 'static,
+    S: StateMachine<Op>,
+    M: StateMachine<Op>,
+    S::Output: PartialEq<M::Output>,
+    NewS: Fn() -> S + 'static,
+    NewM: Fn() -> M + 'static,
+{
+    fuzz_test::<Vec<Op>, _, ReturnBool>(
+        #[no_coverage]
+        move |operations: &Vec<Op>| {
+            let (mut system, mut model) = (new_system(), new_model());
+            run_operations(
+                operations,
+                #[no_coverage]
+                |operation| {
+                    let (output, expected) = (system.apply(operation), model.apply(operation));
+                    if output != expected {
+                        let mut hasher = DefaultHasher::new();
+                        std::mem::discriminant(operation).hash(&mut hasher);
+                        hash_output_variants(&output, &expected, &mut hasher);
+                        return Err(TestFailure {
+                            display: format!(
+                                "the system returned {:?}, but the model returned {:?}",
+                                output, expected
+                            ),
+                            id: hasher.finish(),
+                            frames: vec![],
+                        });
+                    }
+                    system.check_postconditions().map_err(postcondition_failure)
+                },
+            )
+        },
+    )
+}
+
+/// Run `step` on each operation until it fails, and record the failure along with the index of the failing step.
+///
+/// If a step panics, the test failure recorded by the panic hook is given the index of the step as well.
+#[no_coverage]
+fn run_operations<Op: Debug>(operations: &[Op], mut step: impl FnMut(&Op) -> Result<(), TestFailure>) -> bool {
+    let mut index = 0;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(
+        #[no_coverage]
+        || {
+            for operation in operations {
+                step(operation)?;
+                index += 1;
+            }
+            Ok(())
+        },
+    ));
+    let describe_step = #[no_coverage]
+    |failure: &mut TestFailure| {
+        failure.display = format!("step {} ({:?}): {}", index, operations[index], failure.display);
+    };
+    match result {
+        Ok(Ok(())) => true,
+        Ok(Err(mut failure)) => {
+            describe_step(&mut failure);
+            record_test_failure(failure);
+            false
+        }
+        Err(panic) => {
+            if let Some(failure) = unsafe { TEST_FAILURE.as_mut() } {
+                describe_step(failure);
+            }
+            std::panic::resume_unwind(panic)
+        }
+    }
+}
+
+/// The test failure caused by the violation of a postcondition of a [`StateMachine`]
+#[no_coverage]
+fn postcondition_failure(message: String) -> TestFailure {
+    let mut hasher = DefaultHasher::new();
+    message.hash(&mut hasher);
+    TestFailure {
+        display: message,
+        id: hasher.finish(),
+        frames: vec![],
+    }
+}
+
+/// The value of the `--seed` argument passed by `cargo fuzzcheck`, if any.
+///
+/// It must be known before the mutator and pools are created, which is why it cannot wait
+/// until [`arguments_from_cargo_fuzzcheck`](FuzzerBuilder4::arguments_from_cargo_fuzzcheck) is called.
+/// Other invalid arguments are reported by that method instead.
+#[no_coverage]
+fn seed_from_cargo_fuzzcheck() -> Result<Option<u64>, ArgumentsError> {
+    let arguments = match std::env::var("FUZZCHECK_ARGS") {
+        Ok(arguments) => arguments,
+        Err(_) => return Ok(None),
+    };
+    let arguments = split_string_by_whitespace(&arguments);
+    match options_parser().parse(arguments) {
+        Ok(matches) => seed_from_matches(&matches),
+        Err(_) => Ok(None),
+    }
+}
+
+impl<T, F> FuzzerBuilder1<T, F>
+where
+    T: ?Sized,
+    F: Fn(&T) -> bool + 'static,
+{
+    /// Create the builder of the given test function, seeded by `cargo fuzzcheck` if it was given a seed
+    #[no_coverage]
+    fn new(test_function: F) -> Self {
+        let seed = match seed_from_cargo_fuzzcheck() {
+            Ok(seed) => seed,
+            Err(e) => {
+                println!("{}", e);
+                std::process::exit(1);
+            }
+        };
+        if let Some(seed) = seed {
+            fastrand::seed(seed);
+        }
+        FuzzerBuilder1 {
+            test_function,
+            seed,
+            _phantom: PhantomData,
+        }
+    }
+    /// Seed the random number generators of the mutator, sensor, and pool that are created afterwards.
+    ///
+    /// This works by seeding the thread-local generator of `fastrand`, from which mutators and pools
+    /// draw the seeds of their own generators when they are constructed. So the seed only applies to the
+    /// mutator and pool created after this call, on the same thread, and before any other code uses
+    /// that generator. A mutator created before [`fuzz_test(..)`](fuzz_test) is called is not seeded,
+    /// and neither is one that draws its randomness from elsewhere.
+    ///
+    /// If these conditions hold, two fuzzer runs given the same seed, corpus, and number of iterations
+    /// test the same sequence of test cases. The seed can also be given to `cargo fuzzcheck` with `--seed`,
+    /// in which case it is applied when [`fuzz_test(..)`](fuzz_test) is called.
+    ///
+    /// The seed is also given to the [arguments](Arguments) of the fuzzer, which uses it to seed the random
+    /// number generators that it creates when it is launched.
+    #[no_coverage]
+    pub fn seed(self, seed: u64) -> Self {
+        fastrand::seed(seed);
+        let mut x = self;
+        x.seed = Some(seed);
+        x
     }
 }
 
@@ -366,6 +852,7 @@ where
         FuzzerBuilder2 {
             test_function: self.test_function.test_function(),
             mutator,
+            seed: self.seed,
             _phantom: PhantomData,
         }
     }
@@ -403,6 +890,7 @@ where
             test_function: self.test_function,
             mutator: self.mutator,
             serializer: Box::new(serializer),
+            seed: self.seed,
             _phantom: PhantomData,
         }
     }
@@ -422,6 +910,7 @@ where
             test_function: self.test_function,
             mutator: self.mutator,
             serializer: Box::new(SerdeSerializer::<V>::default()),
+            seed: self.seed,
             _phantom: PhantomData,
         }
     }
@@ -443,6 +932,7 @@ where
             serializer: self.serializer,
             sensor,
             pool,
+            seed: self.seed,
             _phantom: PhantomData,
         }
     }
@@ -458,9 +948,57 @@ where
             serializer: self.serializer,
             sensor,
             pool,
+            seed: self.seed,
             _phantom: PhantomData,
         }
     }
+    /// Check that the test function does not fail on the corpus saved by `cargo fuzzcheck` for the fuzz
+    /// test called `test_name`, which is in the folder `fuzz/<test_name>/corpus`.
+    ///
+    /// The test cases are only replayed, without any code coverage sensor, so the code does not need to
+    /// be instrumented. This is how the [`#[fuzzcheck::test]`](crate::test) attribute runs a fuzz test that
+    /// is not launched by `cargo fuzzcheck`, such as with a plain `cargo test`. A missing corpus is considered
+    /// empty.
+    #[no_coverage]
+    pub fn corpus_regression_check(self, test_name: &str) -> FuzzerBuilder5<F, M, V, NoopSensor, UnitPool>
+    where
+        F: 'static,
+    {
+        let corpus = format!("fuzz/{}/corpus", test_name);
+        let arguments = [
+            format!("--{}", COMMAND_FLAG),
+            COMMAND_REPLAY.to_owned(),
+            format!("--{}", IN_CORPUS_FLAG),
+            corpus,
+            format!("--{}", NO_OUT_CORPUS_FLAG),
+            format!("--{}", NO_ARTIFACTS_FLAG),
+            format!("--{}", NO_STATS_FLAG),
+        ];
+        let matches = options_parser().parse(arguments).unwrap();
+        let arguments = Arguments::from_matches(&matches, false).unwrap();
+        self.sensor_and_pool(NoopSensor, UnitPool::new(PoolStorageIndex::mock(0)))
+            .arguments(arguments)
+    }
+}
+
+/// Whether the test was launched by `cargo fuzzcheck`, which gives the arguments of the fuzzer through
+/// the `FUZZCHECK_ARGS` environment variable. Used by the [`#[fuzzcheck::test]`](crate::test) attribute.
+#[doc(hidden)]
+#[no_coverage]
+pub fn launched_by_cargo_fuzzcheck() -> bool {
+    std::env::var_os("FUZZCHECK_ARGS").is_some()
+}
+
+/// The name of the test function `function` defined in the module `module_path`, as given to
+/// `cargo fuzzcheck`. Used by the [`#[fuzzcheck::test]`](crate::test) attribute.
+#[doc(hidden)]
+#[no_coverage]
+pub fn test_name(module_path: &str, function: &str) -> String {
+    // the first segment of the module path is the name of the crate, which is not part of the test name
+    match module_path.split_once("::") {
+        Some((_, module_path)) => format!("{}::{}", module_path, function),
+        None => function.to_owned(),
+    }
 }
 
 impl<F, M, V, Sens, P> FuzzerBuilder4<F, M, V, Sens, P>
@@ -471,8 +1009,13 @@ where
     Sens: Sensor,
     P: CompatibleWithObservations<Sens::Observations>,
 {
+    /// Specify the arguments of the fuzzer. If the builder was given a seed with
+    /// [`FuzzerBuilder1::seed`], it replaces the seed of the arguments.
     #[no_coverage]
-    pub fn arguments(self, arguments: Arguments) -> FuzzerBuilder5<F, M, V, Sens, P> {
+    pub fn arguments(self, mut arguments: Arguments) -> FuzzerBuilder5<F, M, V, Sens, P> {
+        if self.seed.is_some() {
+            arguments.seed = self.seed;
+        }
         FuzzerBuilder5 {
             test_function: self.test_function,
             mutator: self.mutator,
@@ -480,6 +1023,7 @@ where
             sensor: self.sensor,
             pool: self.pool,
             arguments,
+            reporter: Box::new(TerminalReporter),
             _phantom: self._phantom,
         }
     }
@@ -523,7 +1067,7 @@ fuzzcheck {minify} --{input_file} "artifacts/crash.json"
         let arguments = std::env::var("FUZZCHECK_ARGS").unwrap();
         let arguments = split_string_by_whitespace(&arguments);
         let matches = parser.parse(arguments).map_err(ArgumentsError::from);
-        let arguments = match matches.and_then(
+        let mut arguments = match matches.and_then(
             #[no_coverage]
             |matches| Arguments::from_matches(&matches, false),
         ) {
@@ -533,6 +1077,9 @@ fuzzcheck {minify} --{input_file} "artifacts/crash.json"
                 std::process::exit(1);
             }
         };
+        if self.seed.is_some() {
+            arguments.seed = self.seed;
+        }
         FuzzerBuilder5 {
             test_function: self.test_function,
             mutator: self.mutator,
@@ -540,6 +1087,7 @@ fuzzcheck {minify} --{input_file} "artifacts/crash.json"
             sensor: self.sensor,
             pool: self.pool,
             arguments,
+            reporter: Box::new(TerminalReporter),
             _phantom: PhantomData,
         }
     }
@@ -572,12 +1120,49 @@ where
         x.arguments.corpus_out = path.map(Path::to_path_buf);
         x
     }
+    /// Set the interval between two reads of the output corpus, or `None` to never read it.
+    ///
+    /// Each read picks up the test cases that other fuzzers, running on the same fuzz target with the
+    /// same output corpus, added to it since the previous read. They are then tested and the interesting
+    /// ones are added to the pool of this fuzzer.
+    #[no_coverage]
+    pub fn corpus_sync_interval(self, interval: Option<Duration>) -> Self {
+        let mut x = self;
+        x.arguments.corpus_sync_interval = interval;
+        x
+    }
     #[no_coverage]
     pub fn artifacts_folder(self, path: Option<&Path>) -> Self {
         let mut x = self;
         x.arguments.artifacts_folder = path.map(Path::to_path_buf);
         x
     }
+    /// Resume the fuzzing session whose statistics were written to the given folder.
+    ///
+    /// The fuzzer regularly saves a checkpoint in the `stats` folder of the session. It contains:
+    /// * the test cases of the pool, along with the number of times each of them was mutated
+    /// * the number of iterations
+    /// * the number of arbitrary test cases that were generated
+    /// * the state returned by [`Pool::checkpoint`](crate::Pool::checkpoint)
+    ///
+    /// When the session is resumed, the test cases of the checkpoint are tested again, which rebuilds the pool,
+    /// after which the number of iterations and the state of the pool are restored. Among the pools provided by
+    /// fuzzcheck, only [`AndPool`] and [`AndSensorAndPool`](crate::sensors_and_pools::AndSensorAndPool) have
+    /// such a state. The other pools rank the test cases again according to what they observe when testing them.
+    ///
+    /// The mutation steps and the step used to generate arbitrary test cases cannot be serialized, so they are
+    /// restored by giving new steps to the mutator as many times as the old ones were. They are therefore the same
+    /// as in the resumed session if the mutator does not rely on randomness to advance its steps. The random
+    /// number generators are not restored.
+    ///
+    /// If the checkpoint is missing or cannot be decoded, for example because the fuzzer was killed while
+    /// writing it, the fuzzer reports it and resumes the session from the input corpus only.
+    #[no_coverage]
+    pub fn resume(self, path: Option<&Path>) -> Self {
+        let mut x = self;
+        x.arguments.resume = path.map(Path::to_path_buf);
+        x
+    }
     #[no_coverage]
     pub fn maximum_complexity(self, max_input_cplx: f64) -> Self {
         let mut x = self;
@@ -596,12 +1181,119 @@ where
         x.arguments.maximum_duration = duration;
         x
     }
+    /// Stop the fuzzer once no test case was added to the corpus for the given number of iterations or duration.
+    #[no_coverage]
+    pub fn stop_after_no_progress(self, limit: Option<NoProgressLimit>) -> Self {
+        let mut x = self;
+        x.arguments.stop_after_no_progress = limit;
+        x
+    }
+    /// Stop the fuzzer once the code coverage reaches the given target.
+    ///
+    /// The coverage is measured on the files whose paths are relative to the current directory, whatever the
+    /// sensor given to the fuzzer.
+    /// If the target is a function or a line that does not match any of their code regions, the fuzzer stops
+    /// immediately with [`ReasonForStopping::InvalidCoverageTarget`](crate::ReasonForStopping::InvalidCoverageTarget).
+    #[no_coverage]
+    pub fn stop_at_coverage(self, target: Option<CoverageTarget>) -> Self {
+        let mut x = self;
+        x.arguments.stop_at_coverage = target;
+        x
+    }
     #[no_coverage]
     pub fn stop_after_first_test_failure(self, stop_after_first_test_failure: bool) -> Self {
         let mut x = self;
         x.arguments.stop_after_first_failure = stop_after_first_test_failure;
         x
     }
+    /// Set the maximum duration of a single execution of the test function.
+    ///
+    /// A test case that takes longer is saved in the `timeout` subfolder of the artifacts folder
+    /// and the fuzzer stops, unless the test function is run in a child process
+    /// (see [`self.fork(..)`](FuzzerBuilder5::fork)). In that case, a timeout is a test failure like
+    /// any other: all timeouts are the same kind of failure, which is saved as an artifact only if the fuzzer
+    /// stops after the first failure, and is otherwise kept in the test failure pool.
+    #[no_coverage]
+    pub fn timeout(self, timeout: Option<Duration>) -> Self {
+        let mut x = self;
+        x.arguments.timeout = timeout;
+        x
+    }
+    /// Run the test function in a child process instead of the fuzzer’s process.
+    ///
+    /// Crashes of the tested code, such as segmentation faults or aborts, are then recorded as
+    /// test failures and the fuzzer keeps running. Only the code coverage counters are
+    /// transferred from the child process to the fuzzer, so the sensor should be based on
+    /// code coverage.
+    #[no_coverage]
+    pub fn fork(self, fork: bool) -> Self {
+        let mut x = self;
+        x.arguments.fork = fork;
+        x
+    }
+    /// Set the number of child processes that run the test function in parallel.
+    ///
+    /// Each child process has its own code coverage counters, but their results are all given
+    /// to the same sensor and pool, and saved in the same output corpus. A value larger than
+    /// 1 implies [`self.fork(true)`](FuzzerBuilder5::fork).
+    #[no_coverage]
+    pub fn jobs(self, jobs: usize) -> Self {
+        let mut x = self;
+        x.arguments.jobs = jobs;
+        x
+    }
+    /// Also guide the fuzzer with the operands of the integer comparisons made by the test function.
+    ///
+    /// The comparisons are recorded by a [`ComparisonsSensor`](crate::sensors_and_pools::ComparisonsSensor),
+    /// which is added to the sensor and pool of the fuzzer. It requires the fuzzed crate to be compiled with
+    /// the comparison tracing of `SanitizerCoverage`, which `cargo fuzzcheck --trace-compares` does.
+    /// It has no effect when the test function runs in a child process.
+    #[no_coverage]
+    pub fn trace_compares(self, trace_compares: bool) -> Self {
+        let mut x = self;
+        x.arguments.trace_compares = trace_compares;
+        x
+    }
+    /// Identify test failures by the `n` innermost frames of their backtrace that belong to the tested crate,
+    /// or by the location of the panic if `None`.
+    ///
+    /// A frame belongs to the tested crate if its source file is inside the current directory. Test failures
+    /// with the same identifier are considered to be caused by the same bug and are grouped together in the
+    /// artifacts folder. Capturing a backtrace is slow, but it only happens when the test function panics.
+    #[no_coverage]
+    pub fn backtrace_frames(self, n: Option<usize>) -> Self {
+        let mut x = self;
+        x.arguments.backtrace_frames = n;
+        x
+    }
+    /// Write each event of the fuzzer, along with the statistics of the pool, as a JSON object on its own line
+    /// to the given file or file descriptor.
+    ///
+    /// This requires the `serde_json_serializer` feature.
+    #[no_coverage]
+    pub fn events(self, events: Option<EventStream>) -> Self {
+        let mut x = self;
+        x.arguments.events = events;
+        x
+    }
+    /// Set the reporter that receives the events of the fuzzer and the messages it wants to show to the user.
+    ///
+    /// By default, they are printed to stdout by a [`TerminalReporter`].
+    #[no_coverage]
+    pub fn reporter<R: Reporter>(self, reporter: R) -> Self {
+        let mut x = self;
+        x.reporter = Box::new(reporter);
+        x
+    }
+    /// Set what the fuzzer does with the files of the corpora that cannot be read or decoded into valid test cases.
+    ///
+    /// By default, they are skipped and listed along with the reason why they were rejected.
+    #[no_coverage]
+    pub fn invalid_corpus(self, policy: InvalidCorpusPolicy) -> Self {
+        let mut x = self;
+        x.arguments.invalid_corpus = policy;
+        x
+    }
     /// Launch the fuzz test!
     #[no_coverage]
     pub fn launch(self) -> FuzzingResult<V> {
@@ -618,6 +1310,7 @@ where
             pool,
             sensor,
             arguments,
+            reporter,
             _phantom,
         } = self;
 
@@ -627,6 +1320,7 @@ where
             serializer,
             Box::new((sensor, pool)),
             arguments,
+            reporter,
         )
     }
 }
@@ -864,3 +1558,122 @@ impl SensorAndPoolBuilder<DiverseSensor, BasicAndDiversePool> {
         SensorAndPoolBuilder { sensor, pool }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzz_test_differential, fuzz_test_model_based, FuzzerBuilder1, StateMachine};
+    use crate::sensors_and_pools::{TestFailure, TEST_FAILURE};
+    use std::sync::Mutex;
+
+    /// The test failures are recorded in a global variable, so the test functions of this module run one at a time
+    static TEST_FAILURE_LOCK: Mutex<()> = Mutex::new(());
+
+    /// The test failure recorded by the test function of the builder on the given value
+    #[no_coverage]
+    fn test_failure<T, F: Fn(&T) -> bool>(builder: &FuzzerBuilder1<T, F>, value: &T) -> TestFailure {
+        let _guard = TEST_FAILURE_LOCK.lock().unwrap_or_else(
+            #[no_coverage]
+            |e| e.into_inner(),
+        );
+        assert!(!(builder.test_function)(value));
+        unsafe { TEST_FAILURE.take() }.unwrap()
+    }
+
+    #[no_coverage]
+    fn failure_id<T, F: Fn(&T) -> bool>(builder: &FuzzerBuilder1<T, F>, value: &T) -> u64 {
+        test_failure(builder, value).id
+    }
+
+    #[no_coverage]
+    fn f(x: &u8) -> Result<u8, u8> {
+        if *x <= 2 {
+            Ok(0)
+        } else if *x < 10 {
+            Ok(*x)
+        } else {
+            Err(*x)
+        }
+    }
+
+    #[no_coverage]
+    fn g(x: &u8) -> Result<u8, u8> {
+        if *x < 20 {
+            Ok(*x)
+        } else {
+            Err(*x)
+        }
+    }
+
+    #[test]
+    fn test_result_failure() {
+        let builder = super::fuzz_test(
+            #[no_coverage]
+            |x: &u8| x.checked_sub(1).ok_or("cannot subtract 1"),
+        );
+        assert!((builder.test_function)(&1));
+        assert_eq!(test_failure(&builder, &0).display, "\"cannot subtract 1\"");
+    }
+
+    #[test]
+    fn test_differential_failure_ids() {
+        let builder = fuzz_test_differential(f, g);
+        assert!((builder.test_function)(&5));
+        // differences between outputs of the same variants are the same kind of test failure
+        assert_eq!(failure_id(&builder, &1), failure_id(&builder, &2));
+        assert_eq!(failure_id(&builder, &15), failure_id(&builder, &16));
+        assert_ne!(failure_id(&builder, &1), failure_id(&builder, &15));
+        // but the failures of two differential fuzz tests are not
+        let other = fuzz_test_differential(g, f);
+        assert_ne!(failure_id(&builder, &1), failure_id(&other, &1));
+    }
+
+    #[derive(Clone, Debug)]
+    enum Op {
+        Set(u8),
+        Get,
+    }
+
+    /// A register that forgets to store zero
+    struct BuggyRegister(Option<u8>);
+    struct Register(Option<u8>);
+
+    impl StateMachine<Op> for BuggyRegister {
+        type Output = Option<u8>;
+        #[no_coverage]
+        fn apply(&mut self, operation: &Op) -> Option<u8> {
+            match operation {
+                Op::Set(0) => None,
+                Op::Set(x) => self.0.replace(*x).and(None),
+                Op::Get => self.0,
+            }
+        }
+    }
+    impl StateMachine<Op> for Register {
+        type Output = Option<u8>;
+        #[no_coverage]
+        fn apply(&mut self, operation: &Op) -> Option<u8> {
+            match operation {
+                Op::Set(x) => self.0.replace(*x).and(None),
+                Op::Get => self.0,
+            }
+        }
+    }
+
+    #[test]
+    fn test_model_based_failure_ids() {
+        let builder = fuzz_test_model_based(
+            #[no_coverage]
+            || BuggyRegister(None),
+            #[no_coverage]
+            || Register(None),
+        );
+        assert!((builder.test_function)(&vec![Op::Set(1), Op::Get]));
+        let none_instead_of_some = failure_id(&builder, &vec![Op::Set(0), Op::Get]);
+        let wrong_value = failure_id(&builder, &vec![Op::Set(1), Op::Set(0), Op::Get]);
+        assert_eq!(
+            wrong_value,
+            failure_id(&builder, &vec![Op::Set(2), Op::Set(0), Op::Get])
+        );
+        assert_ne!(none_instead_of_some, wrong_value);
+    }
+}
@@ -0,0 +1,163 @@
+//! Detection of the moment the code coverage reaches the target given by `--stop-at-coverage`
+
+use std::path::{Path, PathBuf};
+
+use fuzzcheck_common::arg::CoverageTarget;
+
+use super::llvm_coverage::{Coverage, FunctionRecord, MappingRegion};
+use super::CodeCoverageSensor;
+use crate::traits::Sensor;
+
+/// Keeps track of the code regions ever covered by the test function, to tell when a [`CoverageTarget`] is reached.
+///
+/// It reads the coverage counters through its own [`CodeCoverageSensor`], which observes the files relative
+/// to the current directory. It is therefore independent of the sensor used by the fuzzer.
+pub(crate) struct CoverageTargetTracker {
+    sensor: CodeCoverageSensor,
+    target: CoverageTarget,
+    /// Whether each counter of the sensor was ever hit
+    covered: Vec<bool>,
+    count_covered: usize,
+    /// The target is reached as soon as any of these counters is hit. Unused for a target percentage.
+    target_counters: Vec<usize>,
+}
+
+impl CoverageTargetTracker {
+    /// Returns an error message if the target is a function or line that does not match any code region
+    /// of the files relative to the current directory.
+    #[no_coverage]
+    pub(crate) fn new(target: CoverageTarget) -> Result<Self, String> {
+        let sensor = CodeCoverageSensor::observing_only_files_from_current_dir();
+        let code_regions = sensor.code_regions();
+        let target_counters = match &target {
+            CoverageTarget::Percentage(_) => vec![],
+            CoverageTarget::Function(name) => code_regions
+                .iter()
+                .filter(
+                    #[no_coverage]
+                    |(coverage, _, _)| function_has_name(&coverage.function_record.name_function, name),
+                )
+                .flat_map(
+                    #[no_coverage]
+                    |(_, counters, _)| counters.iter().copied(),
+                )
+                .collect(),
+            CoverageTarget::Line { file, line } => innermost_counters_at_line(&code_regions, file, *line),
+        };
+        if !matches!(target, CoverageTarget::Percentage(_)) && target_counters.is_empty() {
+            return Err(format!(
+                "The coverage target {} does not match any code region of the files relative to the current directory.",
+                target
+            ));
+        }
+        let covered = vec![false; sensor.count_instrumented];
+        Ok(Self {
+            sensor,
+            target,
+            covered,
+            count_covered: 0,
+            target_counters,
+        })
+    }
+
+    /// Record the code regions covered by the last execution of the test function, and return whether
+    /// the target is reached.
+    ///
+    /// It must be called before the coverage counters are reset for the next execution.
+    #[no_coverage]
+    pub(crate) fn is_reached(&mut self) -> bool {
+        for (index, _) in self.sensor.get_observations() {
+            if !self.covered[index] {
+                self.covered[index] = true;
+                self.count_covered += 1;
+            }
+        }
+        match self.target {
+            CoverageTarget::Percentage(percentage) => {
+                let count_instrumented = std::cmp::max(self.sensor.count_instrumented, 1);
+                self.count_covered as f64 * 100.0 / count_instrumented as f64 >= percentage
+            }
+            CoverageTarget::Function(_) | CoverageTarget::Line { .. } => self.target_counters.iter().any(
+                #[no_coverage]
+                |&index| self.covered[index],
+            ),
+        }
+    }
+}
+
+/// Whether the demangled name of the function, without its hash, is `name` or ends with `::<name>`
+#[no_coverage]
+fn function_has_name(function_name: &str, name: &str) -> bool {
+    // the names of functions with internal linkage are prefixed by the name of their file
+    let symbol = if rustc_demangle::try_demangle(function_name).is_ok() {
+        function_name
+    } else {
+        function_name.rsplit(&[':', ';'][..]).next().unwrap_or(function_name)
+    };
+    let demangled = format!("{:#}", rustc_demangle::demangle(symbol));
+    demangled == name || demangled.ends_with(&format!("::{}", name))
+}
+
+/// The counters implying the coverage of the smallest code regions containing the given line of the given file
+#[no_coverage]
+fn innermost_counters_at_line(
+    code_regions: &[(&Coverage, Vec<usize>, &[MappingRegion])],
+    file: &Path,
+    line: usize,
+) -> Vec<usize> {
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    // the number of lines spanned by the smallest region containing the line, for each element of `code_regions`
+    let spans = code_regions
+        .iter()
+        .map(
+            #[no_coverage]
+            |(coverage, _, regions)| {
+                let record = &coverage.function_record;
+                regions
+                    .iter()
+                    .filter(
+                        #[no_coverage]
+                        |region| {
+                            region.line_start <= line
+                                && line <= region.line_end
+                                && region_file(record, region).map_or(
+                                    false,
+                                    #[no_coverage]
+                                    |region_file| current_dir.join(region_file).ends_with(file),
+                                )
+                        },
+                    )
+                    .map(
+                        #[no_coverage]
+                        |region| region.line_end - region.line_start,
+                    )
+                    .min()
+            },
+        )
+        .collect::<Vec<_>>();
+    let smallest_span = spans.iter().flatten().min().copied();
+    if smallest_span.is_none() {
+        return vec![];
+    }
+    code_regions
+        .iter()
+        .zip(spans)
+        .filter(
+            #[no_coverage]
+            |(_, span)| *span == smallest_span,
+        )
+        .flat_map(
+            #[no_coverage]
+            |((_, counters, _), _)| counters.iter().copied(),
+        )
+        .collect()
+}
+
+#[no_coverage]
+fn region_file<'a>(record: &'a FunctionRecord, region: &MappingRegion) -> Option<&'a PathBuf> {
+    let file_idx = record.file_id_mapping.filename_indices.iter().position(
+        #[no_coverage]
+        |idx| *idx == region.filename_index,
+    )?;
+    record.filenames.get(file_idx)
+}
@@ -1,13 +1,16 @@
 int __llvm_profile_runtime = 0;
 
-extern unsigned long int __start___llvm_prf_cnts;
-extern unsigned long int __stop___llvm_prf_cnts;
+// The symbols are weak so that a program that is not instrumented, such as a fuzz test
+// checked against its corpus by a plain `cargo test`, can still be linked. They are then null.
 
-extern char __start___llvm_prf_data;
-extern char __stop___llvm_prf_data;
+extern unsigned long int __start___llvm_prf_cnts __attribute__((weak));
+extern unsigned long int __stop___llvm_prf_cnts __attribute__((weak));
 
-extern char __start___llvm_prf_names;
-extern char __stop___llvm_prf_names;
+extern char __start___llvm_prf_data __attribute__((weak));
+extern char __stop___llvm_prf_data __attribute__((weak));
+
+extern char __start___llvm_prf_names __attribute__((weak));
+extern char __stop___llvm_prf_names __attribute__((weak));
 
 char *get_start_prf_data()
 {
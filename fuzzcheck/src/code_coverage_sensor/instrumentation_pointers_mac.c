@@ -1,9 +1,12 @@
 int __llvm_profile_runtime = 0;
 
+// The symbols are weak so that a program that is not instrumented, such as a fuzz test
+// checked against its corpus by a plain `cargo test`, can still be linked. They are then null.
+
 extern unsigned long int
-    CountersStart __asm("section$start$__DATA$__llvm_prf_cnts"); // different based on the platform, so double-check it
+    CountersStart __asm("section$start$__DATA$__llvm_prf_cnts") __attribute__((weak)); // different based on the platform, so double-check it
 extern unsigned long int
-    CountersEnd __asm("section$end$__DATA$__llvm_prf_cnts");
+    CountersEnd __asm("section$end$__DATA$__llvm_prf_cnts") __attribute__((weak));
 
 unsigned long int *get_start_instrumentation_counters()
 {
@@ -15,9 +18,9 @@ unsigned long int *get_end_instrumentation_counters()
 }
 
 extern char
-    PrfDataStart __asm("section$start$__DATA$__llvm_prf_data"); // different based on the platform, so double-check it
+    PrfDataStart __asm("section$start$__DATA$__llvm_prf_data") __attribute__((weak)); // different based on the platform, so double-check it
 extern char
-    PrfDataEnd __asm("section$end$__DATA$__llvm_prf_data");
+    PrfDataEnd __asm("section$end$__DATA$__llvm_prf_data") __attribute__((weak));
 
 char *get_start_prf_data()
 {
@@ -29,9 +32,9 @@ char *get_end_prf_data()
 }
 
 extern char
-    PrfNamesStart __asm("section$start$__DATA$__llvm_prf_names"); // different based on the platform, so double-check it
+    PrfNamesStart __asm("section$start$__DATA$__llvm_prf_names") __attribute__((weak)); // different based on the platform, so double-check it
 extern char
-    PrfNamesEnd __asm("section$end$__DATA$__llvm_prf_names");
+    PrfNamesEnd __asm("section$end$__DATA$__llvm_prf_names") __attribute__((weak));
 
 char *get_start_prf_names()
 {
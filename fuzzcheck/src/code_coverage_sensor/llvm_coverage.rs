@@ -26,6 +26,10 @@ extern "C" {
 #[no_coverage]
 pub unsafe fn get_counters() -> &'static mut [u64] {
     let start = get_start_instrumentation_counters();
+    if start.is_null() {
+        // the binary is not instrumented for code coverage
+        return &mut [];
+    }
     let end = get_end_instrumentation_counters();
     let len = end.offset_from(start) as usize;
     std::slice::from_raw_parts_mut(start, len)
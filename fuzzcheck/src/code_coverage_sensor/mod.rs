@@ -1,7 +1,8 @@
 //! Code coverage analysis
 
+mod coverage_target;
 mod leb128;
-mod llvm_coverage;
+pub(crate) mod llvm_coverage;
 #[cfg(feature = "serde_json_serializer")]
 mod serialized;
 
@@ -10,7 +11,8 @@ use std::convert::TryFrom;
 use std::path::Path;
 use std::{collections::HashMap, path::PathBuf};
 
-use self::llvm_coverage::{get_counters, get_prf_data, read_covmap, Coverage, LLVMCovSections};
+pub(crate) use self::coverage_target::CoverageTargetTracker;
+use self::llvm_coverage::{get_counters, get_prf_data, read_covmap, Coverage, LLVMCovSections, MappingRegion};
 
 /// A sensor that automatically records the code coverage of the program through an array of counters.
 ///
@@ -90,6 +92,44 @@ impl CodeCoverageSensor {
         }
     }
 
+    /// The code regions observed by the sensor, each with the coverage of its function and the indices of the
+    /// counters, as given in the observations, whose activation implies that the region was covered
+    #[no_coverage]
+    pub(crate) fn code_regions(&self) -> Vec<(&Coverage, Vec<usize>, &[MappingRegion])> {
+        let mut code_regions = vec![];
+        let mut idx = 0;
+        for coverage in self.coverage.iter() {
+            let expressions = &coverage.function_record.expressions;
+            // same order as in `Coverage::new`: the single counters first, then the expressions
+            let mut expression_idx_to_counter_idx = HashMap::new();
+            for (i, (e, regions)) in expressions.iter().enumerate() {
+                if e.add_terms.len() == 1 && e.sub_terms.is_empty() {
+                    code_regions.push((coverage, vec![idx], regions.as_slice()));
+                    expression_idx_to_counter_idx.insert(i, idx);
+                    idx += 1;
+                }
+            }
+            for (i, (e, regions)) in expressions.iter().enumerate() {
+                if !(e.add_terms.len() == 1 && e.sub_terms.is_empty()) && !e.add_terms.is_empty() {
+                    code_regions.push((coverage, vec![idx], regions.as_slice()));
+                    expression_idx_to_counter_idx.insert(i, idx);
+                    idx += 1;
+                }
+            }
+            for (regions, from_expression_idxs) in &coverage.function_record.inferred_expressions {
+                let counters = from_expression_idxs
+                    .iter()
+                    .filter_map(
+                        #[no_coverage]
+                        |i| expression_idx_to_counter_idx.get(i).copied(),
+                    )
+                    .collect();
+                code_regions.push((coverage, counters, regions.as_slice()));
+            }
+        }
+        code_regions
+    }
+
     #[no_coverage]
     unsafe fn clear(&mut self) {
         for &coverage_idx in &self.needs_clearing {
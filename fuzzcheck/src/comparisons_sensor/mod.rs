@@ -0,0 +1,239 @@
+//! Observation of the operands of the comparisons made by the program
+
+use std::cell::{Cell, RefCell};
+use std::path::PathBuf;
+
+use ahash::AHashSet;
+
+use crate::traits::{SaveToStatsFolder, Sensor};
+
+#[repr(C)]
+struct ComparedOperands {
+    arg1: u64,
+    arg2: u64,
+    size: u64,
+}
+
+extern "C" {
+    fn fuzzcheck_start_recording_comparisons();
+    fn fuzzcheck_stop_recording_comparisons();
+    fn fuzzcheck_nbr_comparison_sites() -> usize;
+    fn fuzzcheck_comparison_scores() -> *const u8;
+    fn fuzzcheck_touched_comparison_sites(len: *mut usize) -> *const u16;
+    fn fuzzcheck_compared_operands(len: *mut usize) -> *const ComparedOperands;
+}
+
+/// A sensor that records the operands of the integer comparisons made by the program.
+///
+/// It requires the program to be compiled with the comparison tracing of LLVM’s `SanitizerCoverage`,
+/// which is done by `cargo fuzzcheck --trace-compares`. Otherwise, it does not observe anything.
+///
+/// Each comparison is assigned to one of [`count_sites`](ComparisonsSensor::count_sites) comparison sites,
+/// based on its location in the program. The observations of the sensor are a list of `(site, score)`, where
+/// the score of a site is the highest number of equal bits between the two operands of its comparisons, plus
+/// one. It is therefore compatible with [`MaximiseEachCounterPool`](crate::sensors_and_pools::MaximiseEachCounterPool),
+/// which then keeps the test cases that come closest to solving each comparison.
+///
+/// ```no_run
+/// use fuzzcheck::sensors_and_pools::{ComparisonsSensor, MaximiseEachCounterPool};
+/// let sensor = ComparisonsSensor::default();
+/// let pool = MaximiseEachCounterPool::new("max_each_cmp", sensor.count_sites);
+/// ```
+///
+/// The operands of the comparisons are also added to a dictionary that the mutators of integers and of
+/// vectors of bytes draw values from.
+///
+/// There should be only one `ComparisonsSensor` at a time. It does not work when the test function runs in
+/// a child process (see `--fork`). Comparisons of slices, such as `bytes == b"magic number"`, are compiled
+/// to calls to `memcmp` and are not observed, unless they are short enough to be optimised into integer comparisons.
+pub struct ComparisonsSensor {
+    /// The number of comparison sites observed by the sensor
+    pub count_sites: usize,
+}
+
+impl Default for ComparisonsSensor {
+    #[no_coverage]
+    fn default() -> Self {
+        Self {
+            count_sites: unsafe { fuzzcheck_nbr_comparison_sites() },
+        }
+    }
+}
+
+impl Sensor for ComparisonsSensor {
+    type Observations = Vec<(usize, u64)>;
+
+    #[no_coverage]
+    fn start_recording(&mut self) {
+        unsafe { fuzzcheck_start_recording_comparisons() }
+    }
+
+    #[no_coverage]
+    fn stop_recording(&mut self) {
+        unsafe { fuzzcheck_stop_recording_comparisons() }
+        let mut len = 0;
+        let operands = unsafe { fuzzcheck_compared_operands(&mut len) };
+        let operands = unsafe { std::slice::from_raw_parts(operands, len) };
+        DICTIONARY.with(
+            #[no_coverage]
+            |dictionary| {
+                let mut dictionary = dictionary.borrow_mut();
+                for operands in operands {
+                    dictionary.insert(operands.arg1, operands.size as usize);
+                    dictionary.insert(operands.arg2, operands.size as usize);
+                }
+            },
+        );
+        if !operands.is_empty() {
+            HAS_COMPARED_OPERANDS.with(
+                #[no_coverage]
+                |has_compared_operands| has_compared_operands.set(true),
+            );
+        }
+    }
+
+    #[no_coverage]
+    fn get_observations(&mut self) -> Self::Observations {
+        let mut len = 0;
+        let sites = unsafe { fuzzcheck_touched_comparison_sites(&mut len) };
+        let sites = unsafe { std::slice::from_raw_parts(sites, len) };
+        let scores = unsafe { std::slice::from_raw_parts(fuzzcheck_comparison_scores(), self.count_sites) };
+        let mut observations = sites
+            .iter()
+            .map(
+                #[no_coverage]
+                |&site| (site as usize, scores[site as usize] as u64),
+            )
+            .collect::<Vec<_>>();
+        observations.sort_unstable();
+        observations
+    }
+}
+
+impl SaveToStatsFolder for ComparisonsSensor {
+    #[no_coverage]
+    fn save_to_stats_folder(&self) -> Vec<(PathBuf, Vec<u8>)> {
+        vec![]
+    }
+}
+
+/// The maximum number of operands of each size kept in the dictionary
+const MAX_OPERANDS_PER_SIZE: usize = 512;
+
+/// The operands of the comparisons observed by the [`ComparisonsSensor`], sorted by their size in bytes.
+#[derive(Default)]
+struct Dictionary {
+    // indexed by the base-2 logarithm of the size of the operands: 1, 2, 4, and 8 bytes
+    operands: [Vec<u64>; 4],
+    known: [AHashSet<u64>; 4],
+    rng: fastrand::Rng,
+}
+
+impl Dictionary {
+    #[no_coverage]
+    fn insert(&mut self, operand: u64, size: usize) {
+        let i = size.trailing_zeros() as usize;
+        if i >= self.operands.len() || !self.known[i].insert(operand) {
+            return;
+        }
+        let operands = &mut self.operands[i];
+        if operands.len() < MAX_OPERANDS_PER_SIZE {
+            operands.push(operand);
+        } else {
+            let replaced = std::mem::replace(&mut operands[self.rng.usize(..MAX_OPERANDS_PER_SIZE)], operand);
+            self.known[i].remove(&replaced);
+        }
+    }
+}
+
+thread_local! {
+    static DICTIONARY: RefCell<Dictionary> = RefCell::new(Dictionary::default());
+    // the dictionary is never emptied, so this flag avoids borrowing it in the mutators
+    // of programs that do not record comparisons
+    static HAS_COMPARED_OPERANDS: Cell<bool> = Cell::new(false);
+}
+
+/// Whether the [`ComparisonsSensor`] observed any comparison with different operands
+#[no_coverage]
+pub(crate) fn has_compared_operands() -> bool {
+    HAS_COMPARED_OPERANDS.with(Cell::get)
+}
+
+/// Pick a random operand of the given size, in bytes, among the operands of the comparisons observed
+/// by the [`ComparisonsSensor`].
+#[no_coverage]
+pub(crate) fn compared_operand_of_size(rng: &fastrand::Rng, size: usize) -> Option<u64> {
+    DICTIONARY.with(
+        #[no_coverage]
+        |dictionary| {
+            let dictionary = dictionary.borrow();
+            let operands = dictionary.operands.get(size.trailing_zeros() as usize)?;
+            if operands.is_empty() {
+                None
+            } else {
+                Some(operands[rng.usize(..operands.len())])
+            }
+        },
+    )
+}
+
+/// Pick a random operand among the operands of the comparisons observed by the [`ComparisonsSensor`],
+/// along with its size in bytes.
+#[no_coverage]
+pub(crate) fn compared_operand(rng: &fastrand::Rng) -> Option<(u64, usize)> {
+    DICTIONARY.with(
+        #[no_coverage]
+        |dictionary| {
+            let dictionary = dictionary.borrow();
+            let nbr_operands = dictionary.operands.iter().map(Vec::len).sum::<usize>();
+            if nbr_operands == 0 {
+                return None;
+            }
+            let mut choice = rng.usize(..nbr_operands);
+            for (i, operands) in dictionary.operands.iter().enumerate() {
+                if choice < operands.len() {
+                    return Some((operands[choice], 1 << i));
+                }
+                choice -= operands.len();
+            }
+            unreachable!()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "C" {
+        fn __sanitizer_cov_trace_cmp4(arg1: u32, arg2: u32);
+    }
+
+    #[test]
+    fn test_comparisons_sensor() {
+        let mut sensor = ComparisonsSensor::default();
+        // comparisons are ignored outside of the recording window
+        unsafe { __sanitizer_cov_trace_cmp4(1, 2) };
+        sensor.start_recording();
+        sensor.stop_recording();
+        assert!(sensor.get_observations().is_empty());
+        assert!(!has_compared_operands());
+
+        sensor.start_recording();
+        unsafe { __sanitizer_cov_trace_cmp4(0x1234_5678, 0x1234_5600) };
+        sensor.stop_recording();
+        let observations = sensor.get_observations();
+        assert_eq!(observations.len(), 1);
+        // 32 bits, of which 4 are different, plus one
+        assert_eq!(observations[0].1, 29);
+
+        assert!(has_compared_operands());
+        let rng = fastrand::Rng::with_seed(0);
+        for _ in 0..10 {
+            let operand = compared_operand_of_size(&rng, 4).unwrap();
+            assert!(operand == 0x1234_5678 || operand == 0x1234_5600);
+            assert_eq!(compared_operand(&rng).unwrap().1, 4);
+        }
+        assert!(compared_operand_of_size(&rng, 8).is_none());
+    }
+}
@@ -0,0 +1,139 @@
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+// Hooks called by the code instrumented with -sanitizer-coverage-trace-compares.
+//
+// While recording, each comparison updates the score of its comparison site, which is
+// the number of equal bits between its two operands, plus one. The operands of the first
+// comparisons are also kept, so that they can be added to the dictionary of the mutators.
+//
+// The hooks can be called concurrently by the threads of the tested code, so the shared state
+// is only updated with atomic operations. They are also weak symbols, such that they can be
+// replaced by the hooks of another runtime linked in the same program.
+
+#define NBR_SITES 4096
+#define MAX_OPERANDS 1024
+
+struct fuzzcheck_compared_operands
+{
+    uint64_t arg1;
+    uint64_t arg2;
+    uint64_t size;
+};
+
+static int is_recording = 0;
+
+static uint8_t scores[NBR_SITES];
+static uint16_t touched_sites[NBR_SITES];
+static size_t nbr_touched_sites = 0;
+
+static struct fuzzcheck_compared_operands operands[MAX_OPERANDS];
+static size_t nbr_operands = 0;
+
+static inline void record_comparison(uintptr_t pc, uint64_t arg1, uint64_t arg2, uint64_t size)
+{
+    if (!__atomic_load_n(&is_recording, __ATOMIC_RELAXED))
+    {
+        return;
+    }
+    size_t site = (size_t)(((uint64_t)pc * 0x9E3779B97F4A7C15ull) >> 32) % NBR_SITES;
+    uint8_t score = (uint8_t)(size * 8 - __builtin_popcountll(arg1 ^ arg2) + 1);
+    uint8_t previous = __atomic_load_n(&scores[site], __ATOMIC_RELAXED);
+    while (previous < score)
+    {
+        if (__atomic_compare_exchange_n(&scores[site], &previous, score, 1, __ATOMIC_RELAXED, __ATOMIC_RELAXED))
+        {
+            // scores are never lower than 1, so only one thread can replace the score 0 of a site
+            if (previous == 0)
+            {
+                size_t i = __atomic_fetch_add(&nbr_touched_sites, 1, __ATOMIC_RELAXED);
+                if (i < NBR_SITES)
+                {
+                    touched_sites[i] = (uint16_t)site;
+                }
+            }
+            break;
+        }
+    }
+    if (arg1 != arg2 && __atomic_load_n(&nbr_operands, __ATOMIC_RELAXED) < MAX_OPERANDS)
+    {
+        size_t i = __atomic_fetch_add(&nbr_operands, 1, __ATOMIC_RELAXED);
+        if (i < MAX_OPERANDS)
+        {
+            operands[i].arg1 = arg1;
+            operands[i].arg2 = arg2;
+            operands[i].size = size;
+        }
+    }
+}
+
+#define PC() ((uintptr_t)__builtin_return_address(0))
+
+__attribute__((weak)) void __sanitizer_cov_trace_cmp1(uint8_t arg1, uint8_t arg2) { record_comparison(PC(), arg1, arg2, 1); }
+__attribute__((weak)) void __sanitizer_cov_trace_cmp2(uint16_t arg1, uint16_t arg2) { record_comparison(PC(), arg1, arg2, 2); }
+__attribute__((weak)) void __sanitizer_cov_trace_cmp4(uint32_t arg1, uint32_t arg2) { record_comparison(PC(), arg1, arg2, 4); }
+__attribute__((weak)) void __sanitizer_cov_trace_cmp8(uint64_t arg1, uint64_t arg2) { record_comparison(PC(), arg1, arg2, 8); }
+
+__attribute__((weak)) void __sanitizer_cov_trace_const_cmp1(uint8_t arg1, uint8_t arg2) { record_comparison(PC(), arg1, arg2, 1); }
+__attribute__((weak)) void __sanitizer_cov_trace_const_cmp2(uint16_t arg1, uint16_t arg2) { record_comparison(PC(), arg1, arg2, 2); }
+__attribute__((weak)) void __sanitizer_cov_trace_const_cmp4(uint32_t arg1, uint32_t arg2) { record_comparison(PC(), arg1, arg2, 4); }
+__attribute__((weak)) void __sanitizer_cov_trace_const_cmp8(uint64_t arg1, uint64_t arg2) { record_comparison(PC(), arg1, arg2, 8); }
+
+// cases[0] is the number of cases, cases[1] is the size of the value in bits,
+// and the remaining elements are the values of the cases
+__attribute__((weak)) void __sanitizer_cov_trace_switch(uint64_t val, uint64_t *cases)
+{
+    uintptr_t pc = PC();
+    uint64_t size = cases[1] / 8;
+    for (uint64_t i = 0; i < cases[0]; i++)
+    {
+        record_comparison(pc + i, val, cases[2 + i], size);
+    }
+}
+
+// SanitizerCoverage only traces comparisons if another kind of coverage is enabled.
+// cargo-fuzzcheck enables the inline boolean flags, which are ignored.
+__attribute__((weak)) void __sanitizer_cov_bool_flag_init(int8_t *start, int8_t *stop)
+{
+    (void)start;
+    (void)stop;
+}
+
+void fuzzcheck_start_recording_comparisons()
+{
+    for (size_t i = 0; i < nbr_touched_sites; i++)
+    {
+        scores[touched_sites[i]] = 0;
+    }
+    nbr_touched_sites = 0;
+    nbr_operands = 0;
+    is_recording = 1;
+}
+
+void fuzzcheck_stop_recording_comparisons()
+{
+    is_recording = 0;
+}
+
+size_t fuzzcheck_nbr_comparison_sites()
+{
+    return NBR_SITES;
+}
+
+const uint8_t *fuzzcheck_comparison_scores()
+{
+    return scores;
+}
+
+const uint16_t *fuzzcheck_touched_comparison_sites(size_t *len)
+{
+    *len = nbr_touched_sites < NBR_SITES ? nbr_touched_sites : NBR_SITES;
+    return touched_sites;
+}
+
+const struct fuzzcheck_compared_operands *fuzzcheck_compared_operands(size_t *len)
+{
+    *len = nbr_operands < MAX_OPERANDS ? nbr_operands : MAX_OPERANDS;
+    return operands;
+}
@@ -0,0 +1,405 @@
+//! Execution of the test function in a child process, such that the fuzzer
+//! survives crashes of the tested code.
+//!
+//! The child process is forked from the fuzzer once it is fully set up. It then
+//! receives serialized test cases through a pipe, runs the test function on them,
+//! and writes the result back through a second pipe. After each execution, the
+//! code coverage counters of the child are copied to a region of memory shared with
+//! the fuzzer, which copies them back into its own counters. The sensors of the fuzzer
+//! can therefore observe the code coverage of the child as if the test function had
+//! been run in-process.
+//!
+//! Note that only the LLVM code coverage counters are shared. Sensors that observe
+//! other parts of the memory of the process will not see the effects of the test function.
+//!
+//! When the child process crashes, it is restarted on the next execution.
+//!
+//! Several fork servers can run test cases in parallel, since each of them has its own
+//! child process and shared memory. Their results must then be received one at a time,
+//! since they are copied to the same code coverage counters. Each child process closes
+//! its copy of the pipes of the other fork servers, so that closing the pipes of a
+//! fork server is always seen by its own child process.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::ptr;
+use std::sync::Mutex;
+
+use crate::code_coverage_sensor::llvm_coverage::get_counters;
+use crate::sensors_and_pools::TestFailure;
+use crate::signals_handler::reset_signal_handlers;
+
+static FORK_ERROR: &str = "the fork server could not be started";
+
+/// The ends of the pipes that the fuzzer keeps for each running child process, which are
+/// inherited by every child process forked afterwards
+static FUZZER_PIPE_FDS: Mutex<Vec<RawFd>> = Mutex::new(vec![]);
+
+/// The outcome of running a test case in the child process
+pub(crate) enum ExecutionResult {
+    /// The test function returned, with or without a test failure
+    Finished(Option<TestFailure>),
+    /// The child process was terminated by the given signal while running the test case
+    Signaled(i32),
+    /// The child process exited with the given status while running the test case
+    Exited(i32),
+}
+
+struct Child {
+    pid: libc::pid_t,
+    /// The pipe through which test cases are sent to the child process
+    requests: File,
+    /// The pipe through which the child process sends back the results
+    responses: File,
+}
+
+/// Runs test cases in a child process. See the [module documentation](self).
+pub(crate) struct ForkServer {
+    shared_counters: *mut u64,
+    counters_len: usize,
+    child: Option<Child>,
+}
+
+impl ForkServer {
+    #[no_coverage]
+    pub(crate) fn new() -> Self {
+        let counters_len = unsafe { get_counters() }.len();
+        // mmap does not accept a length of 0
+        let size = std::cmp::max(counters_len, 1) * std::mem::size_of::<u64>();
+        let shared_counters = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+        if shared_counters == libc::MAP_FAILED {
+            panic!("{}: {}", FORK_ERROR, io::Error::last_os_error());
+        }
+        // writing to the pipe of a dead child process should return an error instead of killing the fuzzer
+        unsafe {
+            libc::signal(libc::SIGPIPE, libc::SIG_IGN);
+        }
+        Self {
+            shared_counters: shared_counters as *mut u64,
+            counters_len,
+            child: None,
+        }
+    }
+
+    /// Run the given test case in the child process, starting a new child process if needed.
+    ///
+    /// `run_test` is only called by the child process, with the content of `input` and a function
+    /// resetting the code coverage counters. It must deserialize the test case, then call the given
+    /// function right before running the test function on it, such that the counters only record
+    /// the code coverage of the test function. It returns the test failure it caused, if any.
+    ///
+    /// When the test function returns, the code coverage counters of the child process are
+    /// copied into the counters of the current process. Otherwise, they are all reset to 0.
+    #[no_coverage]
+    pub(crate) fn execute<F>(&mut self, input: &[u8], run_test: F) -> ExecutionResult
+    where
+        F: FnMut(&[u8], &mut dyn FnMut()) -> Option<TestFailure>,
+    {
+        self.send(input, run_test);
+        self.receive()
+    }
+
+    /// Send the given test case to the child process, starting a new child process if needed.
+    ///
+    /// The child process starts running the test case immediately. Its result must then be
+    /// retrieved with [`self.receive()`](ForkServer::receive) before another test case is sent.
+    #[no_coverage]
+    pub(crate) fn send<F>(&mut self, input: &[u8], run_test: F)
+    where
+        F: FnMut(&[u8], &mut dyn FnMut()) -> Option<TestFailure>,
+    {
+        if self.child.is_none() {
+            self.spawn(run_test);
+        }
+        let child = self.child.as_mut().unwrap();
+        // if the child process is dead, the error is reported by `self.receive()`
+        let _ = child.requests.write_all(&(input.len() as u64).to_le_bytes()).and_then(
+            #[no_coverage]
+            |_| child.requests.write_all(input),
+        );
+    }
+
+    /// Wait for the result of the test case previously given to [`self.send(..)`](ForkServer::send)
+    /// and copy the code coverage counters of the child process into the counters of the current process.
+    #[no_coverage]
+    pub(crate) fn receive(&mut self) -> ExecutionResult {
+        let child = self.child.as_mut().unwrap();
+        let result = Self::read_response(&mut child.responses);
+        let counters = unsafe { get_counters() };
+        match result {
+            Ok(test_failure) => {
+                let shared = unsafe { std::slice::from_raw_parts(self.shared_counters, self.counters_len) };
+                counters.copy_from_slice(shared);
+                ExecutionResult::Finished(test_failure)
+            }
+            Err(_) => {
+                for c in counters.iter_mut() {
+                    *c = 0;
+                }
+                self.reap_child()
+            }
+        }
+    }
+
+    #[no_coverage]
+    fn read_response(responses: &mut File) -> io::Result<Option<TestFailure>> {
+        let mut tag = [0u8; 1];
+        responses.read_exact(&mut tag)?;
+        if tag[0] == 0 {
+            return Ok(None);
+        }
+        let mut id = [0u8; 8];
+        responses.read_exact(&mut id)?;
+        let display = Self::read_string(responses)?;
+        let mut nbr_frames = [0u8; 8];
+        responses.read_exact(&mut nbr_frames)?;
+        let mut frames = vec![];
+        for _ in 0..u64::from_le_bytes(nbr_frames) {
+            frames.push(Self::read_string(responses)?);
+        }
+        Ok(Some(TestFailure {
+            display,
+            id: u64::from_le_bytes(id),
+            frames,
+        }))
+    }
+
+    #[no_coverage]
+    fn read_string(responses: &mut File) -> io::Result<String> {
+        let mut len = [0u8; 8];
+        responses.read_exact(&mut len)?;
+        let mut string = vec![0u8; u64::from_le_bytes(len) as usize];
+        responses.read_exact(&mut string)?;
+        Ok(String::from_utf8_lossy(&string).into_owned())
+    }
+
+    #[no_coverage]
+    fn write_response(responses: &mut File, test_failure: Option<TestFailure>) -> io::Result<()> {
+        if let Some(test_failure) = test_failure {
+            responses.write_all(&[1])?;
+            responses.write_all(&test_failure.id.to_le_bytes())?;
+            Self::write_string(responses, &test_failure.display)?;
+            responses.write_all(&(test_failure.frames.len() as u64).to_le_bytes())?;
+            for frame in &test_failure.frames {
+                Self::write_string(responses, frame)?;
+            }
+            Ok(())
+        } else {
+            responses.write_all(&[0])
+        }
+    }
+
+    #[no_coverage]
+    fn write_string(responses: &mut File, string: &str) -> io::Result<()> {
+        responses.write_all(&(string.len() as u64).to_le_bytes())?;
+        responses.write_all(string.as_bytes())
+    }
+
+    /// Wait for the termination of the child process and return the reason why it terminated
+    #[no_coverage]
+    fn reap_child(&mut self) -> ExecutionResult {
+        // dropping the child closes the pipes, which makes the child process exit if it is still alive
+        let Child {
+            pid,
+            requests,
+            responses,
+        } = self.child.take().unwrap();
+        Self::fuzzer_pipe_fds().retain(
+            #[no_coverage]
+            |&fd| fd != requests.as_raw_fd() && fd != responses.as_raw_fd(),
+        );
+        drop(requests);
+        drop(responses);
+        let mut status = 0;
+        unsafe {
+            libc::waitpid(pid, &mut status, 0);
+        }
+        if libc::WIFSIGNALED(status) {
+            ExecutionResult::Signaled(libc::WTERMSIG(status))
+        } else {
+            ExecutionResult::Exited(libc::WEXITSTATUS(status))
+        }
+    }
+
+    #[no_coverage]
+    fn spawn<F>(&mut self, mut run_test: F)
+    where
+        F: FnMut(&[u8], &mut dyn FnMut()) -> Option<TestFailure>,
+    {
+        let (requests_read, requests_write) = Self::pipe();
+        let (responses_read, responses_write) = Self::pipe();
+        // anything left in the buffer of stdout would otherwise be printed by both processes
+        let _ = io::stdout().flush();
+
+        let pid = unsafe { libc::fork() };
+        if pid < 0 {
+            panic!("{}: {}", FORK_ERROR, io::Error::last_os_error());
+        }
+        if pid > 0 {
+            drop(requests_read);
+            drop(responses_write);
+            Self::fuzzer_pipe_fds().extend([requests_write.as_raw_fd(), responses_read.as_raw_fd()]);
+            self.child = Some(Child {
+                pid,
+                requests: requests_write,
+                responses: responses_read,
+            });
+            return;
+        }
+
+        // in the child process
+        drop(requests_write);
+        drop(responses_read);
+        // otherwise, the other fork servers would not be able to close the pipes of their child process
+        for fd in Self::fuzzer_pipe_fds().drain(..) {
+            unsafe {
+                libc::close(fd);
+            }
+        }
+        unsafe {
+            // crashes must terminate the child process, and interrupting the fuzzer
+            // must not interrupt a test case before the fuzzer can handle it
+            reset_signal_handlers();
+            libc::signal(libc::SIGINT, libc::SIG_IGN);
+        }
+        let mut requests = requests_read;
+        let mut responses = responses_write;
+        let shared = unsafe { std::slice::from_raw_parts_mut(self.shared_counters, self.counters_len) };
+        let counters = unsafe { get_counters() };
+        loop {
+            let mut len = [0u8; 8];
+            if requests.read_exact(&mut len).is_err() {
+                // the fuzzer closed the pipe
+                break;
+            }
+            let mut input = vec![0u8; u64::from_le_bytes(len) as usize];
+            if requests.read_exact(&mut input).is_err() {
+                break;
+            }
+            let test_failure = run_test(
+                &input,
+                &mut #[no_coverage]
+                || {
+                    for c in counters.iter_mut() {
+                        *c = 0;
+                    }
+                },
+            );
+            shared.copy_from_slice(counters);
+            if Self::write_response(&mut responses, test_failure).is_err() {
+                break;
+            }
+        }
+        // exit immediately, without running any destructor or exit handler inherited from the fuzzer
+        unsafe { libc::_exit(0) }
+    }
+
+    #[no_coverage]
+    fn fuzzer_pipe_fds() -> std::sync::MutexGuard<'static, Vec<RawFd>> {
+        FUZZER_PIPE_FDS.lock().unwrap_or_else(
+            #[no_coverage]
+            |e| e.into_inner(),
+        )
+    }
+
+    /// Create a pipe that is closed when the process executes another program, such that the programs
+    /// launched by the test function do not keep it open
+    #[no_coverage]
+    fn pipe() -> (File, File) {
+        let mut fds = [0; 2];
+        #[cfg(target_os = "linux")]
+        let result = unsafe { libc::pipe2(fds.as_mut_ptr(), libc::O_CLOEXEC) };
+        // pipe2 is not available on macOS
+        #[cfg(not(target_os = "linux"))]
+        let result = unsafe {
+            let result = libc::pipe(fds.as_mut_ptr());
+            if result == 0 {
+                libc::fcntl(fds[0], libc::F_SETFD, libc::FD_CLOEXEC);
+                libc::fcntl(fds[1], libc::F_SETFD, libc::FD_CLOEXEC);
+            }
+            result
+        };
+        if result < 0 {
+            panic!("{}: {}", FORK_ERROR, io::Error::last_os_error());
+        }
+        unsafe { (File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])) }
+    }
+}
+
+impl Drop for ForkServer {
+    #[no_coverage]
+    fn drop(&mut self) {
+        if let Some(child) = &self.child {
+            unsafe {
+                libc::kill(child.pid, libc::SIGKILL);
+            }
+            self.reap_child();
+        }
+        let size = std::cmp::max(self.counters_len, 1) * std::mem::size_of::<u64>();
+        unsafe {
+            libc::munmap(self.shared_counters as *mut libc::c_void, size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::io::{AsRawFd, RawFd};
+
+    use super::{ExecutionResult, ForkServer};
+    use crate::sensors_and_pools::TestFailure;
+
+    /// The flags of the given file descriptor, or `None` if it is closed
+    #[no_coverage]
+    fn fd_flags(fd: RawFd) -> Option<i32> {
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFD) };
+        (flags >= 0).then_some(flags)
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_child_process_only_keeps_its_own_pipes() {
+        let mut first = ForkServer::new();
+        let result = first.execute(
+            &[],
+            #[no_coverage]
+            |_, _| None,
+        );
+        assert!(matches!(result, ExecutionResult::Finished(None)));
+        let first_child = first.child.as_ref().unwrap();
+        let first_fds = [first_child.requests.as_raw_fd(), first_child.responses.as_raw_fd()];
+
+        let mut second = ForkServer::new();
+        let result = second.execute(
+            &[],
+            #[no_coverage]
+            |_, _| {
+                if first_fds.iter().any(
+                    #[no_coverage]
+                    |&fd| fd_flags(fd).is_some(),
+                ) {
+                    return Some(TestFailure::new(
+                        "the pipes of the other fork server are open".to_owned(),
+                        1,
+                    ));
+                }
+                None
+            },
+        );
+        assert!(matches!(result, ExecutionResult::Finished(None)));
+        let second_child = second.child.as_ref().unwrap();
+        for fd in [second_child.requests.as_raw_fd(), second_child.responses.as_raw_fd()] {
+            assert_eq!(fd_flags(fd).unwrap() & libc::FD_CLOEXEC, libc::FD_CLOEXEC);
+        }
+    }
+}
@@ -1,20 +1,27 @@
+use crate::code_coverage_sensor::CoverageTargetTracker;
 use crate::data_structures::RcSlab;
+use crate::fork_server::{ExecutionResult, ForkServer};
 use crate::sensors_and_pools::{
-    AndSensorAndPool, NoopSensor, TestFailure, TestFailurePool, TestFailureSensor, UnitPool, TEST_FAILURE,
+    AndSensorAndPool, ComparisonsSensor, MaximiseEachCounterPool, NoopSensor, TestFailure, TestFailurePool,
+    TestFailureSensor, UnitPool, TEST_FAILURE,
 };
-use crate::signals_handler::set_signal_handlers;
-use crate::traits::{CorpusDelta, Mutator, SaveToStatsFolder, SensorAndPool, Serializer};
-use crate::world::World;
-use crate::{CSVField, FuzzedInput, ToCSV};
-use fuzzcheck_common::arg::{Arguments, FuzzerCommand};
+use crate::signals_handler::{set_signal_handlers, start_timer, stop_timer};
+use crate::traits::{CorpusDelta, Mutator, Reporter, SaveToStatsFolder, SensorAndPool, Serializer};
+use crate::world::{ArtifactKind, RejectionReason, World};
+use crate::{CSVField, FuzzedInput, MutationTrace, ToCSV};
+use fuzzcheck_common::arg::{Arguments, FuzzerCommand, NoProgressLimit, COMMAND_MERGE, COMMAND_MINIFY_CORPUS};
 use fuzzcheck_common::{FuzzerEvent, FuzzerStats};
 use libc::{SIGABRT, SIGALRM, SIGBUS, SIGFPE, SIGINT, SIGSEGV, SIGTERM, SIGTRAP};
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
 use std::process::exit;
 use std::result::Result;
+use std::time::Duration;
 
 static WRITE_STATS_ERROR: &str = "the stats could not be written to the file system";
 static WORLD_NEW_ERROR: &str = "an IO operation failed when setting up the fuzzer";
@@ -22,6 +29,19 @@ static SERIALIZER_FROM_DATA_ERROR: &str = "the file could not be decoded into a
 static READ_INPUT_FILE_ERROR: &str = "the input file could not be read";
 static SAVE_ARTIFACTS_ERROR: &str = "the artifact could not be saved";
 static UPDATE_CORPUS_ERROR: &str = "the corpus could not be updated on the file system";
+static REJECTED_CORPUS_FILES_ERROR: &str = "the rejected corpus files could not be quarantined";
+static READ_CHECKPOINT_ERROR: &str = "the checkpoint of the resumed fuzzing session could not be read";
+
+/// The name of the file, in the stats folder, containing the checkpoint from which the fuzzing session can be resumed
+static CHECKPOINT_FILE: &str = "checkpoint.bin";
+
+/// The content of a checkpoint: the number of iterations, the number of arbitrary test cases generated,
+/// the serialized test cases of the pool along with their number of mutations and generation, and the
+/// state of the sensor and pool
+type Checkpoint = (u64, u64, Vec<(Vec<u8>, u64, u64)>, Vec<u8>);
+
+/// On average, one in every `CROSSOVER_PERIOD` mutations of an input of the pool combines it with another one
+const CROSSOVER_PERIOD: usize = 8;
 
 static mut DID_FIND_ANY_TEST_FAILURE: bool = false;
 
@@ -46,7 +66,73 @@ pub enum ReasonForStopping<T> {
     ExhaustedAllPossibleMutations,
     MaxIterationsReached,
     MaxDurationReached,
+    /// No test case was added to the corpus for the duration or number of iterations given by `--stop-after-no-progress`
+    NoProgress,
+    /// The code coverage reached the target given by `--stop-at-coverage`
+    CoverageTargetReached,
+    /// The function or line given by `--stop-at-coverage` does not match any code region
+    InvalidCoverageTarget,
     LaunchedFuzzcheckWithoutCfgFuzzing,
+    /// Every test case of the input corpus was tested by the `cmin` command
+    MinifiedCorpus,
+    /// Every test case of the input corpora was tested by the `merge` command
+    MergedCorpora,
+    /// Every test case of the given folders was tested by the `replay` command
+    Replayed(ReplayReport),
+    /// Some corpus files could not be used as test cases and the invalid corpus policy is
+    /// [`InvalidCorpusPolicy::Fail`](crate::InvalidCorpusPolicy::Fail)
+    InvalidCorpus,
+}
+
+/// The outcome of the `replay` command for each test case it ran.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayReport {
+    pub results: Vec<(PathBuf, ReplayOutcome)>,
+}
+
+/// The outcome of running the test function on a single test case with the `replay` command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayOutcome {
+    Passed,
+    /// The test function failed, with the given message
+    Failed(String),
+    /// The test function did not finish before the timeout
+    TimedOut,
+    /// The file could not be decoded into a valid test case
+    Invalid,
+}
+
+impl ReplayReport {
+    /// Whether any test case failed or timed out
+    #[no_coverage]
+    pub fn found_test_failure(&self) -> bool {
+        self.results.iter().any(
+            #[no_coverage]
+            |(_, outcome)| matches!(outcome, ReplayOutcome::Failed(_) | ReplayOutcome::TimedOut),
+        )
+    }
+
+    /// A one-line summary of the report, giving the number of test cases for each outcome
+    #[no_coverage]
+    pub fn summary(&self) -> String {
+        let (mut passed, mut failed, mut timed_out, mut invalid) = (0, 0, 0, 0);
+        for (_, outcome) in &self.results {
+            match outcome {
+                ReplayOutcome::Passed => passed += 1,
+                ReplayOutcome::Failed(_) => failed += 1,
+                ReplayOutcome::TimedOut => timed_out += 1,
+                ReplayOutcome::Invalid => invalid += 1,
+            }
+        }
+        format!(
+            "replayed {} test cases: {} passed, {} failed, {} timed out, {} invalid",
+            self.results.len(),
+            passed,
+            failed,
+            timed_out,
+            invalid
+        )
+    }
 }
 
 /// The index to a test case in the fuzzer’s storage.
@@ -73,15 +159,28 @@ struct FuzzerState<T: Clone, M: Mutator<T>> {
     pool_storage: RcSlab<FuzzedInput<T, M>>,
     /// The step given to the mutator when the fuzzer wants to create a new arbitrary test case
     arbitrary_step: M::ArbitraryStep,
+    /// The number of times `arbitrary_step` was given to the mutator, which is saved in the checkpoints
+    arbitrary_inputs: usize,
     /// The index of the test case that is being tested
     input_idx: FuzzerInputIndex<FuzzedInput<T, M>>,
+    /// The mutation that was applied to the test case of the pool that is being tested, along with the trace of
+    /// the test case it was combined with if it is a crossover. It is used to record the mutation trace of new
+    /// corpus entries and artifacts.
+    current_mutation: Option<(M::UnmutateToken, Option<MutationTrace>)>,
     /// Various statistics about the fuzzer run
     fuzzer_stats: FuzzerStats,
+    /// The time since the start of the fuzzer and the number of iterations when a test case was last added to the corpus
+    last_progress: (Duration, usize),
+    /// Tells when the code coverage reaches the target given by `--stop-at-coverage`, if any
+    coverage_target: Option<CoverageTargetTracker>,
 
     settings: Arguments,
     serializer: Box<dyn Serializer<Value = T>>,
     /// The world handles effects
     world: World,
+    /// Run the test function in child processes, if the fuzzer was launched with `--fork` or `--jobs`.
+    /// There is one fork server per job.
+    fork_servers: Vec<ForkServer>,
 }
 
 impl<T: Clone, M: Mutator<T>> Drop for FuzzerState<T, M> {
@@ -102,6 +201,30 @@ impl<T: Clone, M: Mutator<T>> FuzzerState<T, M> {
             FuzzerInputIndex::Pool(idx) => Some(&pool_storage[idx.0]),
         }
     }
+
+    /// The mutation trace of the test case that is being tested, or an empty trace if mutation traces are not saved
+    #[no_coverage]
+    fn get_input_trace(
+        world: &World,
+        fuzzer_input_idx: &FuzzerInputIndex<FuzzedInput<T, M>>,
+        pool_storage: &RcSlab<FuzzedInput<T, M>>,
+        mutator: &M,
+        current_mutation: &Option<(M::UnmutateToken, Option<MutationTrace>)>,
+    ) -> MutationTrace {
+        if !world.saves_mutation_traces() {
+            return MutationTrace::default();
+        }
+        match (fuzzer_input_idx, current_mutation) {
+            (FuzzerInputIndex::None, _) => MutationTrace::default(),
+            (FuzzerInputIndex::Temporary(input), _) => input.trace.clone(),
+            (FuzzerInputIndex::Pool(idx), Some((token, crossover_with))) => {
+                let input = &pool_storage[idx.0];
+                let mutation = mutator.describe_mutation(&input.value, token);
+                input.trace.with_mutation(mutation, crossover_with.as_ref())
+            }
+            (FuzzerInputIndex::Pool(idx), None) => pool_storage[idx.0].trace.clone(),
+        }
+    }
 }
 
 #[no_coverage]
@@ -125,6 +248,7 @@ where
     fn save_to_stats_folder(&self) -> Vec<(std::path::PathBuf, Vec<u8>)> {
         let mut contents = self.sensor_and_pool.save_to_stats_folder();
         contents.extend(self.world.save_to_stats_folder());
+        contents.push((PathBuf::from(CHECKPOINT_FILE), self.checkpoint()));
         contents
     }
 }
@@ -138,21 +262,158 @@ where
         self.world.write_stats_content(self.save_to_stats_folder())
     }
 
+    /// Serialize the number of iterations, the test cases of the pool, the progress of their mutation steps and
+    /// of the arbitrary step, and the state of the sensor and pool such that the fuzzing session can be resumed later.
+    /// See [`FuzzerBuilder5::resume`](crate::builder::FuzzerBuilder5::resume).
     #[no_coverage]
-    fn receive_signal(&mut self, signal: i32) -> ! {
-        self.world.report_event(
-            FuzzerEvent::CaughtSignal(signal as i32),
-            Some((&self.fuzzer_stats, self.sensor_and_pool.stats().as_ref())),
+    fn checkpoint(&self) -> Vec<u8> {
+        let inputs = self
+            .pool_storage
+            .keys()
+            .map(
+                #[no_coverage]
+                |key| {
+                    let input = &self.pool_storage[key];
+                    (
+                        self.serializer.to_data(&input.value),
+                        input.mutations as u64,
+                        input.generation as u64,
+                    )
+                },
+            )
+            .collect::<Vec<_>>();
+        let checkpoint: Checkpoint = (
+            self.fuzzer_stats.total_number_of_runs as u64,
+            self.arbitrary_inputs as u64,
+            inputs,
+            self.sensor_and_pool.checkpoint(),
         );
+        bincode::serialize(&checkpoint).unwrap()
+    }
+
+    /// Read the checkpoint saved in the given stats folder.
+    ///
+    /// A missing, truncated, or incompatible checkpoint is reported, and the session is then resumed from the
+    /// input corpus only.
+    #[no_coverage]
+    fn read_checkpoint(&mut self, stats_folder: &Path) -> Option<Checkpoint> {
+        let checkpoint = self
+            .world
+            .read_input_file(&stats_folder.join(CHECKPOINT_FILE))
+            .map_err(
+                #[no_coverage]
+                |e| e.to_string(),
+            )
+            .and_then(
+                #[no_coverage]
+                |content| {
+                    bincode::deserialize::<Checkpoint>(&content).map_err(
+                        #[no_coverage]
+                        |e| e.to_string(),
+                    )
+                },
+            );
+        match checkpoint {
+            Ok(checkpoint) => Some(checkpoint),
+            Err(error) => {
+                self.world.report_message(&format!(
+                    "{}: {}. The session is resumed from the input corpus only.",
+                    READ_CHECKPOINT_ERROR, error
+                ));
+                None
+            }
+        }
+    }
+
+    /// Give a new arbitrary step to the mutator as many times as the arbitrary step of the resumed session was,
+    /// such that the same test cases are not generated again
+    #[no_coverage]
+    fn restore_arbitrary_step(&mut self, arbitrary_inputs: usize) {
+        for _ in 0..arbitrary_inputs {
+            self.arbitrary_inputs += 1;
+            if self
+                .mutator
+                .ordered_arbitrary(&mut self.arbitrary_step, self.settings.max_input_cplx)
+                .is_none()
+            {
+                break;
+            }
+        }
+    }
+
+    /// Give the new mutation step of each test case of the pool to the mutator as many times as its mutation step
+    /// was in the resumed session, and restore its generation
+    #[no_coverage]
+    fn restore_mutation_steps(&mut self, inputs: Vec<(Vec<u8>, u64, u64)>) {
+        let inputs = inputs
+            .into_iter()
+            .map(
+                #[no_coverage]
+                |(content, mutations, generation)| (content, (mutations as usize, generation as usize)),
+            )
+            .collect::<HashMap<_, _>>();
+        let max_cplx = self.settings.max_input_cplx;
+        for key in self.pool_storage.keys().collect::<Vec<_>>() {
+            let content = self.serializer.to_data(&self.pool_storage[key].value);
+            if let Some(&(mutations, generation)) = inputs.get(&content) {
+                let input = &mut self.pool_storage[key];
+                input.generation = generation;
+                for _ in 0..mutations {
+                    if let Some((token, _)) = input.mutate(&mut self.mutator, max_cplx) {
+                        input.unmutate(&self.mutator, token);
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    #[no_coverage]
+    fn write_checkpoint(&mut self) -> Result<(), std::io::Error> {
+        self.world
+            .write_stats_content(vec![(PathBuf::from(CHECKPOINT_FILE), self.checkpoint())])
+    }
+
+    #[no_coverage]
+    fn receive_signal(&mut self, signal: i32) -> ! {
+        let event = if signal == SIGALRM {
+            FuzzerEvent::Timeout
+        } else {
+            FuzzerEvent::CaughtSignal(signal as i32)
+        };
+        self.world
+            .report_event(event, Some((&self.fuzzer_stats, self.sensor_and_pool.stats().as_ref())));
 
         match signal {
             SIGABRT | SIGBUS | SIGSEGV | SIGFPE | SIGALRM | SIGTRAP => {
                 if let Some(input) = Self::get_input(&self.input_idx, &self.pool_storage) {
                     let cplx = input.complexity(&self.mutator);
                     let content = self.serializer.to_data(&input.value);
-                    let _ = self.world.save_artifact(content, cplx, self.serializer.extension());
+                    let trace = Self::get_input_trace(
+                        &self.world,
+                        &self.input_idx,
+                        &self.pool_storage,
+                        &self.mutator,
+                        &self.current_mutation,
+                    );
+                    let _ = self.world.save_mutation_trace(&self.world.hash(&content), &trace);
+                    let (kind, status) = if signal == SIGALRM {
+                        (ArtifactKind::Timeout, TerminationStatus::Timeout)
+                    } else {
+                        (ArtifactKind::Failure, TerminationStatus::Crash)
+                    };
+                    let (test_failure, _) = execution_outcome(ExecutionResult::Signaled(signal), self.settings.timeout);
+                    let _ = self.world.save_artifact(
+                        content,
+                        cplx,
+                        kind,
+                        self.serializer.extension(),
+                        test_failure.as_ref(),
+                        self.fuzzer_stats.total_number_of_runs,
+                    );
                     self.write_stats().expect(WRITE_STATS_ERROR);
-                    exit(TerminationStatus::Crash as i32);
+                    exit(status as i32);
                 } else {
                     self.world.report_event(
                         FuzzerEvent::CrashNoInput,
@@ -170,6 +431,7 @@ where
     }
     #[no_coverage]
     fn arbitrary_input(&mut self) -> Option<(FuzzedInput<T, M>, f64)> {
+        self.arbitrary_inputs += 1;
         if let Some((v, cplx)) = self
             .mutator
             .ordered_arbitrary(&mut self.arbitrary_step, self.settings.max_input_cplx)
@@ -181,6 +443,94 @@ where
             None
         }
     }
+    /// Deserialize and validate the given test case
+    #[no_coverage]
+    fn decode_input(&self, value: &[u8]) -> Result<FuzzedInput<T, M>, RejectionReason> {
+        let (value, cache) = decode_value(self.serializer.as_ref(), &self.mutator, value)?;
+        let mutation_step = self.mutator.default_mutation_step(&value, &cache);
+        Ok(FuzzedInput::new(value, cache, mutation_step, 0))
+    }
+    /// Deserialize and validate the given test cases, ignoring the invalid ones
+    #[no_coverage]
+    fn decode_inputs(&self, values: Vec<Vec<u8>>) -> Vec<FuzzedInput<T, M>> {
+        values
+            .into_iter()
+            .filter_map(
+                #[no_coverage]
+                |value| self.decode_input(&value).ok(),
+            )
+            .collect()
+    }
+    /// Deserialize and validate the test cases of the given corpus files.
+    ///
+    /// The invalid files are rejected and must then be handled with
+    /// [`self.world.handle_rejected_corpus_files()`](World::handle_rejected_corpus_files).
+    #[no_coverage]
+    fn decode_corpus_files(&mut self, files: Vec<(PathBuf, Vec<u8>)>) -> Vec<FuzzedInput<T, M>> {
+        let mut inputs = vec![];
+        for (path, value) in files {
+            match self.decode_input(&value) {
+                Ok(input) => {
+                    self.world.accept_corpus_file(path);
+                    inputs.push(input);
+                }
+                Err(reason) => self.world.reject_corpus_file(path, reason),
+            }
+        }
+        inputs
+    }
+    /// Mutate the input of the pool at the given index.
+    ///
+    /// From time to time, the input is instead combined with another input of the pool, if the mutator
+    /// supports it. In that case, the trace of the other input is returned along with the unmutate token.
+    #[no_coverage]
+    fn mutate_pool_input(
+        pool_storage: &mut RcSlab<FuzzedInput<T, M>>,
+        sensor_and_pool: &mut Box<dyn SensorAndPool>,
+        mutator: &mut M,
+        idx: PoolStorageIndex,
+        max_cplx: f64,
+    ) -> Option<(M::UnmutateToken, f64, Option<MutationTrace>)> {
+        if fastrand::usize(..CROSSOVER_PERIOD) == 0 {
+            if let Some(other_idx) = sensor_and_pool.get_random_index() {
+                if other_idx != idx {
+                    let other = &pool_storage[other_idx.0];
+                    let (other_value, other_cache) = (other.value.clone(), other.cache.clone());
+                    let other_trace = other.trace.clone();
+                    let input = &mut pool_storage[idx.0];
+                    if let Some((token, cplx)) = input.crossover(mutator, &other_value, &other_cache, max_cplx) {
+                        return Some((token, cplx, Some(other_trace)));
+                    }
+                }
+            }
+        }
+        let (token, cplx) = pool_storage[idx.0].mutate(mutator, max_cplx)?;
+        Some((token, cplx, None))
+    }
+    /// Mutate an input of the pool, or generate a new one if the pool is empty, and return a copy of it
+    /// along with its complexity. The input of the pool is left unchanged.
+    #[no_coverage]
+    fn next_candidate(&mut self) -> Option<(FuzzedInput<T, M>, f64)> {
+        if let Some(idx) = self.sensor_and_pool.get_random_index() {
+            let (unmutate_token, cplx, crossover_with) = Self::mutate_pool_input(
+                &mut self.pool_storage,
+                &mut self.sensor_and_pool,
+                &mut self.mutator,
+                idx,
+                self.settings.max_input_cplx,
+            )?;
+            let input = &mut self.pool_storage[idx.0];
+            let mut candidate = input.clone();
+            if self.world.saves_mutation_traces() {
+                let mutation = self.mutator.describe_mutation(&input.value, &unmutate_token);
+                candidate.trace = input.trace.with_mutation(mutation, crossover_with.as_ref());
+            }
+            input.unmutate(&self.mutator, unmutate_token);
+            Some((candidate, cplx))
+        } else {
+            self.arbitrary_input()
+        }
+    }
     #[no_coverage]
     unsafe fn set_up_signal_handler(&mut self) {
         let ptr = self as *mut Self;
@@ -217,17 +567,34 @@ where
         world: World,
     ) -> Self {
         let arbitrary_step = mutator.default_arbitrary_step();
+        // the replay command must run every test case, even after one of them crashes
+        let fork = settings.fork || settings.jobs > 1 || matches!(settings.command, FuzzerCommand::Replay { .. });
+        let fork_servers = if fork {
+            (0..std::cmp::max(settings.jobs, 1))
+                .map(
+                    #[no_coverage]
+                    |_| ForkServer::new(),
+                )
+                .collect()
+        } else {
+            vec![]
+        };
         Fuzzer {
             state: FuzzerState {
                 sensor_and_pool,
                 pool_storage: RcSlab::new(),
                 mutator,
                 arbitrary_step,
+                arbitrary_inputs: 0,
                 input_idx: FuzzerInputIndex::None,
+                current_mutation: None,
                 fuzzer_stats: FuzzerStats::default(),
+                last_progress: (Duration::default(), 0),
+                coverage_target: None,
                 settings,
                 serializer,
                 world,
+                fork_servers,
             },
             test,
         }
@@ -235,16 +602,16 @@ where
 
     #[no_coverage]
     fn test_and_process_input(&mut self, cplx: f64) -> Result<(), ReasonForStopping<T>> {
+        let timeout = self.state.settings.timeout;
+        let backtrace_frames = self.state.settings.backtrace_frames;
         let Fuzzer {
             state:
                 FuzzerState {
-                    mutator,
                     sensor_and_pool,
                     pool_storage,
                     input_idx,
-                    fuzzer_stats,
                     serializer,
-                    world,
+                    fork_servers,
                     ..
                 },
             test,
@@ -254,52 +621,140 @@ where
         // we have verified in the caller function that there is an input
         let input = FuzzerState::<T, M>::get_input(input_idx, pool_storage).unwrap();
 
-        std::panic::set_hook(Box::new(
-            #[no_coverage]
-            move |panic_info| {
-                let mut hasher = DefaultHasher::new();
-                panic_info.location().hash(&mut hasher);
-                unsafe {
-                    TEST_FAILURE = Some(TestFailure {
-                        display: format!("{}", panic_info),
-                        id: hasher.finish(),
-                    });
-                }
-            },
-        ));
-
         sensor_and_pool.start_recording();
-        let result = catch_unwind(AssertUnwindSafe(
-            #[no_coverage]
-            || (test)(input.value.borrow()),
-        ));
-        let _ = std::panic::take_hook();
-        let test_failure = match result {
-            Ok(false) => unsafe {
-                TEST_FAILURE = Some(TestFailure {
-                    display: "test function returned false".to_string(),
-                    id: 0,
-                });
-                true
-            },
-            Err(_) => {
-                // the panic handler already changed the value of TEST_FAILURE
-                // so we don't need to do anything
-                true
-            }
-            Ok(true) => false,
+        let result = if let Some(fork_server) = fork_servers.first_mut() {
+            let content = serializer.to_data(&input.value);
+            fork_server.execute(
+                &content,
+                fork_server_test(test.as_ref(), serializer.as_ref(), timeout, backtrace_frames),
+            )
+        } else {
+            run_test_in_process(test.as_ref(), input.value.borrow(), timeout, backtrace_frames)
         };
-        if test_failure {
+        self.process_execution_result(result, cplx)
+    }
+
+    /// Run the given inputs, in parallel if there are several fork servers
+    #[no_coverage]
+    fn test_and_process_inputs(&mut self, inputs: Vec<(FuzzedInput<T, M>, f64)>) -> Result<(), ReasonForStopping<T>> {
+        let jobs = self.state.fork_servers.len();
+        if jobs <= 1 {
+            for (input, cplx) in inputs {
+                self.state.input_idx = FuzzerInputIndex::Temporary(input);
+                self.test_and_process_input(cplx)?;
+            }
+            return Ok(());
+        }
+        let mut inputs = inputs.into_iter().peekable();
+        while inputs.peek().is_some() {
+            let batch = inputs.by_ref().take(jobs).collect();
+            self.test_and_process_batch(batch)?;
+        }
+        Ok(())
+    }
+
+    /// Send each input of the batch to a different fork server, and then process their results one by one
+    #[no_coverage]
+    fn test_and_process_batch(&mut self, batch: Vec<(FuzzedInput<T, M>, f64)>) -> Result<(), ReasonForStopping<T>> {
+        let timeout = self.state.settings.timeout;
+        let backtrace_frames = self.state.settings.backtrace_frames;
+        let Fuzzer {
+            state:
+                FuzzerState {
+                    serializer,
+                    fork_servers,
+                    ..
+                },
+            test,
+            ..
+        } = self;
+        assert!(batch.len() <= fork_servers.len());
+        for ((input, _), fork_server) in batch.iter().zip(fork_servers.iter_mut()) {
+            let content = serializer.to_data(&input.value);
+            fork_server.send(
+                &content,
+                fork_server_test(test.as_ref(), serializer.as_ref(), timeout, backtrace_frames),
+            );
+        }
+        let mut outcome = Ok(());
+        for (i, (input, cplx)) in batch.into_iter().enumerate() {
+            if outcome.is_err() {
+                // the response must still be received, otherwise it would be taken for the result
+                // of the next test case sent to this fork server
+                let _ = self.state.fork_servers[i].receive();
+                continue;
+            }
+            self.state.input_idx = FuzzerInputIndex::Temporary(input);
+            // the counters of the fork server are copied to the global counters, so
+            // the recording must start before the result is received
+            self.state.sensor_and_pool.start_recording();
+            let result = self.state.fork_servers[i].receive();
+            outcome = self.process_execution_result(result, cplx);
+        }
+        outcome
+    }
+
+    /// Give the result of the execution of the current input to the sensor and pool, and update the corpus accordingly.
+    ///
+    /// It must be called after `self.state.sensor_and_pool.start_recording()` and the execution of the test function.
+    #[no_coverage]
+    fn process_execution_result(&mut self, result: ExecutionResult, cplx: f64) -> Result<(), ReasonForStopping<T>> {
+        let FuzzerState {
+            mutator,
+            sensor_and_pool,
+            pool_storage,
+            input_idx,
+            current_mutation,
+            fuzzer_stats,
+            last_progress,
+            coverage_target,
+            settings,
+            serializer,
+            world,
+            ..
+        } = &mut self.state;
+
+        // we have verified in the caller function that there is an input
+        let input = FuzzerState::<T, M>::get_input(input_idx, pool_storage).unwrap();
+
+        let (test_failure, timed_out) = execution_outcome(result, settings.timeout);
+        let is_failure = test_failure.is_some();
+        unsafe {
+            TEST_FAILURE = test_failure.clone();
+        }
+        if is_failure {
             unsafe {
                 DID_FIND_ANY_TEST_FAILURE = true;
             }
         }
         sensor_and_pool.stop_recording();
-        if test_failure && self.state.settings.stop_after_first_failure {
+        // a timeout is a test failure like any other: it is only saved as an artifact if the fuzzer stops
+        // at the first failure, and otherwise goes to the test failure pool, which keeps the simplest ones
+        if timed_out {
+            world.report_event(
+                FuzzerEvent::Timeout,
+                Some((fuzzer_stats, sensor_and_pool.stats().as_ref())),
+            );
+        }
+        if is_failure && settings.stop_after_first_failure {
             let serialized_input = serializer.to_data(&input.value);
-            self.state
-                .world
-                .save_artifact(serialized_input, cplx, serializer.extension())
+            let trace = FuzzerState::get_input_trace(world, input_idx, pool_storage, mutator, current_mutation);
+            world
+                .save_mutation_trace(&world.hash(&serialized_input), &trace)
+                .expect(WRITE_STATS_ERROR);
+            world
+                .save_artifact(
+                    serialized_input,
+                    cplx,
+                    if timed_out {
+                        ArtifactKind::Timeout
+                    } else {
+                        ArtifactKind::Failure
+                    },
+                    serializer.extension(),
+                    test_failure.as_ref(),
+                    fuzzer_stats.total_number_of_runs,
+                )
                 .expect(SAVE_ARTIFACTS_ERROR);
             return Err(ReasonForStopping::TestFailure(input.value.clone()));
         }
@@ -323,12 +778,31 @@ where
             } else {
                 vec![]
             };
+            let trace = if add_ref_count > 0 {
+                let name = world.hash(&content);
+                let trace = FuzzerState::get_input_trace(world, input_idx, pool_storage, mutator, current_mutation)
+                    .with_name(&name);
+                world.save_mutation_trace(&name, &trace).expect(WRITE_STATS_ERROR);
+                trace
+            } else {
+                MutationTrace::default()
+            };
             world
-                .update_corpus(input_id, content, &deltas, serializer.extension())
+                .update_corpus(
+                    input_id,
+                    content,
+                    cplx,
+                    &deltas,
+                    serializer.extension(),
+                    test_failure.as_ref(),
+                    fuzzer_stats.total_number_of_runs,
+                )
                 .expect(UPDATE_CORPUS_ERROR);
             world.report_event(event, Some((fuzzer_stats, sensor_and_pool.stats().as_ref())));
             if add_ref_count > 0 {
-                let new_input = input.new_source(mutator);
+                *last_progress = (world.elapsed_time_since_start(), fuzzer_stats.total_number_of_runs);
+                let mut new_input = input.new_source(mutator);
+                new_input.trace = trace;
                 // here I don't check the complexity of the new input,
                 // but because of the way mutators work (real possibility of
                 // inconsistent complexities), then its complexity may be higher
@@ -341,6 +815,12 @@ where
                 }
             }
         }
+        // the coverage is not necessarily related to the observations of the sensor, so it is checked after each execution
+        if let Some(coverage_target) = coverage_target {
+            if coverage_target.is_reached() {
+                return Err(ReasonForStopping::CoverageTargetReached);
+            }
+        }
 
         Ok(())
     }
@@ -358,12 +838,15 @@ where
         loop {
             if let Some(idx) = sensor_and_pool.get_random_index() {
                 *input_idx = FuzzerInputIndex::Pool(idx);
-                let input = &mut pool_storage[idx.0];
-                let generation = input.generation;
-                if let Some((unmutate_token, cplx)) = input.mutate(mutator, settings.max_input_cplx) {
+                let generation = pool_storage[idx.0].generation;
+                if let Some((unmutate_token, cplx, crossover_with)) =
+                    FuzzerState::mutate_pool_input(pool_storage, sensor_and_pool, mutator, idx, settings.max_input_cplx)
+                {
+                    self.state.current_mutation = Some((unmutate_token, crossover_with));
                     if cplx < self.state.settings.max_input_cplx {
                         self.test_and_process_input(cplx)?;
                     }
+                    let (unmutate_token, _) = self.state.current_mutation.take().unwrap();
 
                     // Retrieving the input may fail because the input may have been deleted
                     if let Some(input) = self.state.pool_storage.get_mut(idx.0) {
@@ -398,24 +881,64 @@ where
         }
     }
 
+    /// Mutate or generate one input per fork server and run them in parallel
     #[no_coverage]
-    fn process_initial_inputs(&mut self) -> Result<(), ReasonForStopping<T>> {
-        let mut inputs: Vec<FuzzedInput<T, M>> = self
+    fn process_next_inputs_in_parallel(&mut self) -> Result<(), ReasonForStopping<T>> {
+        let jobs = self.state.fork_servers.len();
+        let mut batch = Vec::with_capacity(jobs);
+        for _ in 0..jobs {
+            if let Some((input, cplx)) = self.state.next_candidate() {
+                if cplx < self.state.settings.max_input_cplx {
+                    batch.push((input, cplx));
+                }
+            } else if batch.is_empty() {
+                self.state.world.report_event(
+                    FuzzerEvent::End,
+                    Some((&self.state.fuzzer_stats, self.state.sensor_and_pool.stats().as_ref())),
+                );
+                return Err(ReasonForStopping::ExhaustedAllPossibleMutations);
+            } else {
+                break;
+            }
+        }
+        self.test_and_process_batch(batch)
+    }
+
+    /// Handle the rejected corpus files, stopping the fuzzer if the
+    /// [`InvalidCorpusPolicy`](crate::InvalidCorpusPolicy) says so
+    #[no_coverage]
+    fn handle_rejected_corpus_files(&mut self) -> Result<(), ReasonForStopping<T>> {
+        if self
             .state
             .world
-            .read_input_corpus()
-            .expect(READ_INPUT_FILE_ERROR)
-            .into_iter()
-            .filter_map(
+            .handle_rejected_corpus_files()
+            .expect(REJECTED_CORPUS_FILES_ERROR)
+        {
+            Err(ReasonForStopping::InvalidCorpus)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[no_coverage]
+    fn process_initial_inputs(&mut self) -> Result<(), ReasonForStopping<T>> {
+        let mut values = vec![];
+        let checkpoint = match self.state.settings.resume.clone() {
+            Some(resumed_stats_folder) => self.state.read_checkpoint(&resumed_stats_folder),
+            None => None,
+        };
+        if let Some((_, arbitrary_inputs, inputs, _)) = &checkpoint {
+            values.extend(inputs.iter().map(
                 #[no_coverage]
-                |value| {
-                    let value = self.state.serializer.from_data(&value)?;
-                    let cache = self.state.mutator.validate_value(&value)?;
-                    let mutation_step = self.state.mutator.default_mutation_step(&value, &cache);
-                    Some(FuzzedInput::new(value, cache, mutation_step, 0))
-                },
-            )
-            .collect();
+                |(content, _, _)| content.clone(),
+            ));
+            // before the new arbitrary test cases below are generated
+            self.state.restore_arbitrary_step(*arbitrary_inputs as usize);
+        }
+        let mut inputs = self.state.decode_inputs(values);
+        let files = self.state.world.read_input_corpus().expect(READ_INPUT_FILE_ERROR);
+        inputs.extend(self.state.decode_corpus_files(files));
+        self.handle_rejected_corpus_files()?;
 
         for _ in 0..100 {
             if let Some((input, _)) = self.state.arbitrary_input() {
@@ -432,21 +955,309 @@ where
         // assert!(!inputs.is_empty());
 
         self.state.world.set_checkpoint_instant();
-        for input in inputs {
-            let cplx = input.complexity(&self.state.mutator);
-            self.state.input_idx = FuzzerInputIndex::Temporary(input);
-            self.test_and_process_input(cplx)?;
+        let inputs = inputs
+            .into_iter()
+            .map(
+                #[no_coverage]
+                |input| {
+                    let cplx = input.complexity(&self.state.mutator);
+                    (input, cplx)
+                },
+            )
+            .collect();
+        self.test_and_process_inputs(inputs)?;
+
+        // the pool was rebuilt from the test cases of the checkpoint, its state can now be restored
+        if let Some((total_number_of_runs, _, inputs, sensor_and_pool_checkpoint)) = checkpoint {
+            self.state.fuzzer_stats.total_number_of_runs = total_number_of_runs as usize;
+            self.state.fuzzer_stats.number_of_runs_since_last_reset_time = total_number_of_runs as usize;
+            self.state.restore_mutation_steps(inputs);
+            self.state
+                .sensor_and_pool
+                .restore_checkpoint(&sensor_and_pool_checkpoint);
         }
+        Ok(())
+    }
+
+    /// Test the inputs that other fuzzers added to the output corpus since it was last read
+    #[no_coverage]
+    fn sync_corpus(&mut self) -> Result<(), ReasonForStopping<T>> {
+        let files = self
+            .state
+            .world
+            .read_new_output_corpus_files()
+            .expect(READ_INPUT_FILE_ERROR);
+        let inputs = self.state.decode_corpus_files(files);
+        self.handle_rejected_corpus_files()?;
+        let inputs = inputs
+            .into_iter()
+            .filter_map(
+                #[no_coverage]
+                |input| {
+                    let cplx = input.complexity(&self.state.mutator);
+                    if cplx <= self.state.settings.max_input_cplx {
+                        Some((input, cplx))
+                    } else {
+                        None
+                    }
+                },
+            )
+            .collect();
+        self.test_and_process_inputs(inputs)
+    }
 
+    /// Test every input of the input corpus, from the simplest to the most complex.
+    ///
+    /// The output corpus then only contains the test cases kept by the pool, which are
+    /// the simplest ones for each observation made by the sensor.
+    #[no_coverage]
+    fn minify_corpus(&mut self) -> Result<(), ReasonForStopping<T>> {
+        self.state.world.report_event(
+            FuzzerEvent::Start,
+            Some((&self.state.fuzzer_stats, self.state.sensor_and_pool.stats().as_ref())),
+        );
+        let files = self.state.world.read_input_corpus().expect(READ_INPUT_FILE_ERROR);
+        let inputs = self.state.decode_corpus_files(files);
+        self.handle_rejected_corpus_files()?;
+        let mut inputs = self.with_complexities(inputs);
+        // a pool replaces a test case by a simpler one with the same observations, so the test cases
+        // are tested in order of increasing complexity to avoid writing files that are later removed
+        inputs.sort_by(
+            #[no_coverage]
+            |a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal),
+        );
+        self.state.world.set_checkpoint_instant();
+        self.test_and_process_inputs(inputs)?;
+        self.state.world.report_event(
+            FuzzerEvent::Done,
+            Some((&self.state.fuzzer_stats, self.state.sensor_and_pool.stats().as_ref())),
+        );
         Ok(())
     }
 
+    /// Test the test cases of the output corpus, then the ones of each given corpus, in order.
+    ///
+    /// Only the test cases that lead to new observations are added to the output corpus. The files
+    /// that cannot be decoded into valid test cases are handled at the end.
+    #[no_coverage]
+    fn merge_corpora(&mut self, corpora: &[PathBuf]) -> Result<(), ReasonForStopping<T>> {
+        self.state.world.report_event(
+            FuzzerEvent::Start,
+            Some((&self.state.fuzzer_stats, self.state.sensor_and_pool.stats().as_ref())),
+        );
+        self.state.world.set_checkpoint_instant();
+        // the test cases already in the output corpus take precedence over the merged ones
+        let files = self
+            .state
+            .world
+            .read_new_output_corpus_files()
+            .expect(READ_INPUT_FILE_ERROR);
+        let inputs = self.state.decode_corpus_files(files);
+        self.test_and_process_inputs(self.with_complexities(inputs))?;
+
+        for corpus in corpora {
+            let files = self.state.world.read_corpus_files(corpus).expect(READ_INPUT_FILE_ERROR);
+            let inputs = self.state.decode_corpus_files(files);
+            self.test_and_process_inputs(self.with_complexities(inputs))?;
+        }
+        self.state.world.report_event(
+            FuzzerEvent::Done,
+            Some((&self.state.fuzzer_stats, self.state.sensor_and_pool.stats().as_ref())),
+        );
+        self.handle_rejected_corpus_files()?;
+        Ok(())
+    }
+
+    /// Run the test function on every test case of the given folders, without stopping at the first failure
+    #[no_coverage]
+    fn replay(&mut self, folders: &[PathBuf]) -> ReplayReport {
+        self.state.world.report_event(FuzzerEvent::Start, None);
+        let timeout = self.state.settings.timeout;
+        let backtrace_frames = self.state.settings.backtrace_frames;
+        let mut report = ReplayReport::default();
+        for folder in folders {
+            let files = self.state.world.read_corpus_files(folder).expect(READ_INPUT_FILE_ERROR);
+            for (path, value) in files {
+                let input = match self.state.decode_input(&value) {
+                    Ok(input) => {
+                        self.state.world.accept_corpus_file(path.clone());
+                        input
+                    }
+                    Err(reason) => {
+                        self.state.world.reject_corpus_file(path.clone(), reason);
+                        report.results.push((path, ReplayOutcome::Invalid));
+                        continue;
+                    }
+                };
+                let result = if let Some(fork_server) = self.state.fork_servers.first_mut() {
+                    fork_server.execute(
+                        &value,
+                        fork_server_test(
+                            self.test.as_ref(),
+                            self.state.serializer.as_ref(),
+                            timeout,
+                            backtrace_frames,
+                        ),
+                    )
+                } else {
+                    // the signal handler needs the input if the test function crashes
+                    self.state.input_idx = FuzzerInputIndex::Temporary(input);
+                    let input = FuzzerState::get_input(&self.state.input_idx, &self.state.pool_storage).unwrap();
+                    run_test_in_process(self.test.as_ref(), input.value.borrow(), timeout, backtrace_frames)
+                };
+                self.state.fuzzer_stats.total_number_of_runs += 1;
+                let outcome = match execution_outcome(result, timeout) {
+                    (_, true) => ReplayOutcome::TimedOut,
+                    (Some(test_failure), _) => ReplayOutcome::Failed(test_failure.display),
+                    (None, _) => ReplayOutcome::Passed,
+                };
+                report.results.push((path, outcome));
+            }
+        }
+        self.state.input_idx = FuzzerInputIndex::None;
+        // the rejected files are already reported as invalid test cases, so they do not stop the replay
+        let _ = self
+            .state
+            .world
+            .handle_rejected_corpus_files()
+            .expect(REJECTED_CORPUS_FILES_ERROR);
+        self.state.world.report_replay(&report);
+        if report.found_test_failure() {
+            unsafe {
+                DID_FIND_ANY_TEST_FAILURE = true;
+            }
+        }
+        report
+    }
+
+    /// Minify a failing test case by greedy delta-debugging, using the mutator’s `ordered_simplify` method
+    ///
+    /// The first simpler candidate that fails the test in the same way as the given test case, i.e. with a
+    /// test failure of the same id, replaces the test case, which is then simplified again until none of its
+    /// candidates fail in that way. Returns the simplified test case, its complexity, and its test failure, or
+    /// `None` if the given test case does not fail or none of its candidates fail in the same way.
+    #[no_coverage]
+    fn simplify_failing_input(&mut self, value: &T) -> Option<(T, f64, TestFailure)> {
+        let timeout = self.state.settings.timeout;
+        let backtrace_frames = self.state.settings.backtrace_frames;
+        let maximum_iterations = self.state.settings.maximum_iterations;
+        let Fuzzer {
+            state:
+                FuzzerState {
+                    mutator,
+                    pool_storage,
+                    input_idx,
+                    fuzzer_stats,
+                    serializer,
+                    fork_servers,
+                    ..
+                },
+            test,
+            ..
+        } = self;
+        let mutator = &*mutator;
+
+        // run the test function on the given value and return its test failure, if any
+        let mut execute = #[no_coverage]
+        |value: T, cache: M::Cache| -> Option<TestFailure> {
+            let mutation_step = mutator.default_mutation_step(&value, &cache);
+            // the signal handler needs the input if the test function crashes
+            *input_idx = FuzzerInputIndex::Temporary(FuzzedInput::new(value, cache, mutation_step, 0));
+            let input = FuzzerState::get_input(input_idx, pool_storage).unwrap();
+            let result = if let Some(fork_server) = fork_servers.first_mut() {
+                let content = serializer.to_data(&input.value);
+                fork_server.execute(
+                    &content,
+                    fork_server_test(test.as_ref(), serializer.as_ref(), timeout, backtrace_frames),
+                )
+            } else {
+                run_test_in_process(test.as_ref(), input.value.borrow(), timeout, backtrace_frames)
+            };
+            execution_outcome(result, timeout).0
+        };
+
+        let cache = mutator.validate_value(value).unwrap();
+        fuzzer_stats.total_number_of_runs += 1;
+        let failure_id = execute(value.clone(), cache)?.id;
+
+        let mut simplest: Option<(T, f64, TestFailure)> = None;
+        loop {
+            let current = simplest.as_ref().map_or(
+                value,
+                #[no_coverage]
+                |(value, _, _)| value,
+            );
+            let current_cache = mutator.validate_value(current).unwrap();
+            let current_cplx = mutator.complexity(current, &current_cache);
+            let mut simpler = None;
+            mutator.ordered_simplify(
+                current,
+                &current_cache,
+                &mut #[no_coverage]
+                |candidate| {
+                    if fuzzer_stats.total_number_of_runs >= maximum_iterations {
+                        return false;
+                    }
+                    let cache = if let Some(cache) = mutator.validate_value(&candidate) {
+                        cache
+                    } else {
+                        return false;
+                    };
+                    let cplx = mutator.complexity(&candidate, &cache);
+                    if cplx > current_cplx {
+                        return false;
+                    }
+                    fuzzer_stats.total_number_of_runs += 1;
+                    match execute(candidate.clone(), cache) {
+                        Some(test_failure) if test_failure.id == failure_id => {
+                            simpler = Some((candidate, cplx, test_failure));
+                            true
+                        }
+                        _ => false,
+                    }
+                },
+            );
+            if let Some(simpler) = simpler {
+                simplest = Some(simpler);
+            } else {
+                break;
+            }
+        }
+        *input_idx = FuzzerInputIndex::None;
+        simplest
+    }
+
+    #[no_coverage]
+    fn with_complexities(&self, inputs: Vec<FuzzedInput<T, M>>) -> Vec<(FuzzedInput<T, M>, f64)> {
+        inputs
+            .into_iter()
+            .map(
+                #[no_coverage]
+                |input| {
+                    let cplx = input.complexity(&self.state.mutator);
+                    (input, cplx)
+                },
+            )
+            .collect()
+    }
+
     #[no_coverage]
     fn main_loop(&mut self, minify: bool) -> Result<!, ReasonForStopping<T>> {
         self.state.world.report_event(
             FuzzerEvent::Start,
             Some((&self.state.fuzzer_stats, self.state.sensor_and_pool.stats().as_ref())),
         );
+        if let (FuzzerCommand::Fuzz, Some(target)) =
+            (&self.state.settings.command, &self.state.settings.stop_at_coverage)
+        {
+            match CoverageTargetTracker::new(target.clone()) {
+                Ok(coverage_target) => self.state.coverage_target = Some(coverage_target),
+                Err(message) => {
+                    self.state.world.report_message(&message);
+                    return Err(ReasonForStopping::InvalidCoverageTarget);
+                }
+            }
+        }
         if !minify {
             self.process_initial_inputs()?;
             self.state.world.report_event(
@@ -457,32 +1268,319 @@ where
 
         self.state.world.set_checkpoint_instant();
         let mut next_milestone = (self.state.fuzzer_stats.total_number_of_runs + 10) * 2;
+        let corpus_sync_interval = if minify {
+            None
+        } else {
+            self.state.settings.corpus_sync_interval
+        };
+        let mut next_corpus_sync =
+            self.state.world.elapsed_time_since_start() + corpus_sync_interval.unwrap_or_default();
+        // the test cases of the initial corpus do not count as progress
+        self.state.last_progress = (
+            self.state.world.elapsed_time_since_start(),
+            self.state.fuzzer_stats.total_number_of_runs,
+        );
         loop {
             let duration_since_beginning = self.state.world.elapsed_time_since_start();
             if duration_since_beginning > self.state.settings.maximum_duration {
                 return Err(ReasonForStopping::MaxDurationReached);
             }
+            if let Some(corpus_sync_interval) = corpus_sync_interval {
+                if duration_since_beginning >= next_corpus_sync {
+                    self.sync_corpus()?;
+                    next_corpus_sync = duration_since_beginning + corpus_sync_interval;
+                }
+            }
             if self.state.fuzzer_stats.total_number_of_runs >= self.state.settings.maximum_iterations {
                 return Err(ReasonForStopping::MaxIterationsReached);
             }
-            self.process_next_input()?;
+            if let Some(limit) = self.state.settings.stop_after_no_progress {
+                let (last_progress_time, last_progress_iteration) = self.state.last_progress;
+                let no_progress = match limit {
+                    NoProgressLimit::Duration(duration) => {
+                        duration_since_beginning.saturating_sub(last_progress_time) >= duration
+                    }
+                    NoProgressLimit::Iterations(iterations) => {
+                        self.state.fuzzer_stats.total_number_of_runs - last_progress_iteration >= iterations
+                    }
+                };
+                if no_progress {
+                    return Err(ReasonForStopping::NoProgress);
+                }
+            }
+            if self.state.fork_servers.len() > 1 {
+                self.process_next_inputs_in_parallel()?;
+            } else {
+                self.process_next_input()?;
+            }
             if self.state.fuzzer_stats.total_number_of_runs >= next_milestone {
                 update_fuzzer_stats(&mut self.state.fuzzer_stats, &mut self.state.world);
                 self.state.world.report_event(
                     FuzzerEvent::Pulse,
                     Some((&self.state.fuzzer_stats, self.state.sensor_and_pool.stats().as_ref())),
                 );
+                self.state.write_checkpoint().expect(WRITE_STATS_ERROR);
                 next_milestone = self.state.fuzzer_stats.total_number_of_runs * 2;
             }
         }
     }
 }
 
+/// Run the test function on the given value and record its failure, if any, in `TEST_FAILURE`.
+///
+/// If `backtrace_frames` is `Some(n)`, the id of a panic is derived from the `n` innermost frames of its
+/// backtrace that belong to the tested crate, instead of from its location.
+///
+/// Returns `true` if the test failed.
+#[no_coverage]
+fn run_test<T: ?Sized>(
+    test: &dyn Fn(&T) -> bool,
+    value: &T,
+    timeout: Option<Duration>,
+    backtrace_frames: Option<usize>,
+) -> bool {
+    unsafe {
+        TEST_FAILURE = None;
+    }
+    std::panic::set_hook(Box::new(
+        #[no_coverage]
+        move |panic_info| {
+            let frames = backtrace_frames.map(tested_crate_frames).unwrap_or_default();
+            let mut hasher = DefaultHasher::new();
+            if frames.is_empty() {
+                panic_info.location().hash(&mut hasher);
+            } else {
+                frames.hash(&mut hasher);
+            }
+            unsafe {
+                TEST_FAILURE = Some(TestFailure {
+                    display: format!("{}", panic_info),
+                    id: hasher.finish(),
+                    frames,
+                });
+            }
+        },
+    ));
+    if let Some(timeout) = timeout {
+        start_timer(timeout);
+    }
+    let result = catch_unwind(AssertUnwindSafe(
+        #[no_coverage]
+        || (test)(value),
+    ));
+    if timeout.is_some() {
+        stop_timer();
+    }
+    let _ = std::panic::take_hook();
+    match result {
+        Ok(false) => unsafe {
+            // the test function may have recorded a more precise failure, such as the error it returned
+            if TEST_FAILURE.is_none() {
+                TEST_FAILURE = Some(TestFailure {
+                    display: "test function returned false".to_string(),
+                    id: 0,
+                    frames: vec![],
+                });
+            }
+            true
+        },
+        Err(_) => {
+            // the panic handler already changed the value of TEST_FAILURE
+            // so we don't need to do anything
+            true
+        }
+        Ok(true) => false,
+    }
+}
+
+/// Whether the given source file belongs to the tested crate, i.e. it is inside the current directory
+/// but not inside the source folder of fuzzcheck itself, which can be there when fuzzcheck is a path
+/// dependency of the tested crate
+#[no_coverage]
+fn is_tested_crate_file(file: &Path, current_dir: &Path) -> bool {
+    // the source files of fuzzcheck are recorded relative to its workspace when it is a member of the
+    // workspace of the tested crate, and as absolute paths otherwise
+    let fuzzcheck_src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let is_fuzzcheck_file = file.starts_with(Path::new(file!()).parent().unwrap()) || file.starts_with(fuzzcheck_src);
+    (file.is_relative() || file.starts_with(current_dir)) && !is_fuzzcheck_file
+}
+
+/// Returns the `n` innermost frames of the current backtrace whose source file belongs to the tested
+/// crate, formatted as `function (file:line)`
+#[no_coverage]
+fn tested_crate_frames(n: usize) -> Vec<String> {
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let mut frames = vec![];
+    backtrace::trace(
+        #[no_coverage]
+        |frame| {
+            backtrace::resolve_frame(
+                frame,
+                #[no_coverage]
+                |symbol| {
+                    if let (Some(name), Some(file)) = (symbol.name(), symbol.filename()) {
+                        if is_tested_crate_file(file, &current_dir) {
+                            let file = file.strip_prefix(&current_dir).unwrap_or(file);
+                            frames.push(format!(
+                                "{:#} ({}:{})",
+                                name,
+                                file.display(),
+                                symbol.lineno().unwrap_or(0)
+                            ));
+                        }
+                    }
+                },
+            );
+            frames.len() < n
+        },
+    );
+    frames.truncate(n);
+    frames
+}
+
+/// Deserialize the given content and validate the resulting value with the mutator
+#[no_coverage]
+fn decode_value<T: Clone, M: Mutator<T>>(
+    serializer: &dyn Serializer<Value = T>,
+    mutator: &M,
+    content: &[u8],
+) -> Result<(T, M::Cache), RejectionReason> {
+    let value = serializer.from_data(content).ok_or(RejectionReason::Undecodable)?;
+    let cache = mutator.validate_value(&value).ok_or(RejectionReason::Invalid)?;
+    Ok((value, cache))
+}
+
+/// Run the test function on the given value in the current process
+#[no_coverage]
+fn run_test_in_process<T: ?Sized>(
+    test: &dyn Fn(&T) -> bool,
+    value: &T,
+    timeout: Option<Duration>,
+    backtrace_frames: Option<usize>,
+) -> ExecutionResult {
+    if run_test(test, value, timeout, backtrace_frames) {
+        // if the timer expires, the signal handler saves the input as a timeout artifact
+        ExecutionResult::Finished(unsafe { TEST_FAILURE.clone() })
+    } else {
+        ExecutionResult::Finished(None)
+    }
+}
+
+/// Returns the test failure caused by the execution of a test case, if any, and whether it timed out.
+///
+/// All timeouts are the same kind of test failure.
+#[no_coverage]
+fn execution_outcome(result: ExecutionResult, timeout: Option<Duration>) -> (Option<TestFailure>, bool) {
+    match result {
+        ExecutionResult::Finished(test_failure) => (test_failure, false),
+        ExecutionResult::Signaled(SIGALRM) if timeout.is_some() => (
+            Some(TestFailure {
+                display: "the test function did not finish before the timeout".to_string(),
+                id: timeout_id(),
+                frames: vec![],
+            }),
+            true,
+        ),
+        ExecutionResult::Signaled(signal) => (
+            Some(TestFailure {
+                display: format!("the test function crashed with signal {}", signal),
+                id: crash_id(signal),
+                frames: vec![],
+            }),
+            false,
+        ),
+        ExecutionResult::Exited(status) => (
+            Some(TestFailure {
+                display: format!("the test function exited with status {}", status),
+                id: crash_id(status),
+                frames: vec![],
+            }),
+            false,
+        ),
+    }
+}
+
+/// Returns the function run by the child process of a fork server, which deserializes a test case,
+/// resets the code coverage counters, runs the test function on it, and returns the resulting test failure,
+/// if any
+#[no_coverage]
+fn fork_server_test<'a, T>(
+    test: &'a dyn Fn(&T) -> bool,
+    serializer: &'a dyn Serializer<Value = T>,
+    timeout: Option<Duration>,
+    backtrace_frames: Option<usize>,
+) -> impl FnMut(&[u8], &mut dyn FnMut()) -> Option<TestFailure> + 'a {
+    #[no_coverage]
+    move |content, reset_counters| {
+        let value = serializer.from_data(content).expect(SERIALIZER_FROM_DATA_ERROR);
+        reset_counters();
+        if run_test(test, &value, timeout, backtrace_frames) {
+            unsafe { TEST_FAILURE.clone() }
+        } else {
+            None
+        }
+    }
+}
+
+/// Combine the given sensor and pool with a pool keeping the simplest test case for each kind of test failure,
+/// unless the fuzzer stops after the first one
+#[no_coverage]
+fn with_test_failure_pool(sensor_and_pool: Box<dyn SensorAndPool>, args: &Arguments) -> Box<dyn SensorAndPool> {
+    if args.stop_after_first_failure {
+        sensor_and_pool
+    } else {
+        let test_failure_sensor = TestFailureSensor::default();
+        let test_failure_pool = TestFailurePool::new("test_failures");
+        Box::new(AndSensorAndPool::new(
+            sensor_and_pool,
+            Box::new((test_failure_sensor, test_failure_pool)),
+            10.0,
+            1.0,
+        ))
+    }
+}
+
+/// Combine the given sensor and pool with a pool keeping the test cases that come closest to solving each
+/// comparison of the program, if the comparisons are traced and the test function is run in-process
+#[no_coverage]
+fn with_comparisons_pool(sensor_and_pool: Box<dyn SensorAndPool>, args: &Arguments) -> Box<dyn SensorAndPool> {
+    if !args.trace_compares || args.fork || args.jobs > 1 {
+        sensor_and_pool
+    } else {
+        let comparisons_sensor = ComparisonsSensor::default();
+        let comparisons_pool = MaximiseEachCounterPool::new("max_each_cmp", comparisons_sensor.count_sites);
+        Box::new(AndSensorAndPool::new(
+            sensor_and_pool,
+            Box::new((comparisons_sensor, comparisons_pool)),
+            10.0,
+            1.0,
+        ))
+    }
+}
+
+/// The id of the test failure caused by a timeout
+#[no_coverage]
+fn timeout_id() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    "timeout".hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The id of the test failure caused by a crash of the child process of the fork server
+#[no_coverage]
+fn crash_id(code: i32) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    "crash".hash(&mut hasher);
+    code.hash(&mut hasher);
+    hasher.finish()
+}
+
 pub enum TerminationStatus {
     Success = 0,
     Crash = 1,
     TestFailure = 2,
     Unknown = 3,
+    Timeout = 4,
 }
 
 #[no_coverage]
@@ -492,142 +1590,201 @@ pub fn launch<T, M>(
     serializer: Box<dyn Serializer<Value = T>>,
     sensor_and_pool: Box<dyn SensorAndPool>,
     mut args: Arguments,
+    reporter: Box<dyn Reporter>,
 ) -> FuzzingResult<T>
 where
     T: Clone,
     M: Mutator<T>,
     Fuzzer<T, M>: 'static,
 {
+    if let Some(seed) = args.seed {
+        // the pools created below draw their seeds from this generator
+        fastrand::seed(seed);
+    }
     let command = &args.command;
     let reason_for_stopping = match command {
         FuzzerCommand::Fuzz => {
-            if !args.stop_after_first_failure {
-                let test_failure_sensor = TestFailureSensor::default();
-                let test_failure_pool = TestFailurePool::new("test_failures");
-                let sensor_and_pool = AndSensorAndPool::new(
-                    sensor_and_pool,
-                    Box::new((test_failure_sensor, test_failure_pool)),
-                    10.0,
-                    1.0,
-                );
-                let mut fuzzer = Fuzzer::new(
-                    test,
-                    mutator,
-                    serializer,
-                    Box::new(sensor_and_pool),
-                    args.clone(),
-                    World::new(args.clone()).expect(WORLD_NEW_ERROR),
-                );
-
-                let mut stats_headers = vec![CSVField::String("time".to_string())];
-                stats_headers.extend(fuzzer.state.fuzzer_stats.csv_headers());
-                stats_headers.extend(fuzzer.state.sensor_and_pool.stats().csv_headers());
-                fuzzer
-                    .state
-                    .world
-                    .append_stats_file(&stats_headers)
-                    .expect(WRITE_STATS_ERROR);
-                unsafe { fuzzer.state.set_up_signal_handler() };
-
-                let reason_for_stopping = fuzzer.main_loop(false).unwrap_err();
-                fuzzer.state.write_stats().expect(WRITE_STATS_ERROR);
+            let sensor_and_pool = with_comparisons_pool(sensor_and_pool, &args);
+            let sensor_and_pool = with_test_failure_pool(sensor_and_pool, &args);
+            let mut fuzzer = Fuzzer::new(
+                test,
+                mutator,
+                serializer,
+                sensor_and_pool,
+                args.clone(),
+                World::new(args.clone(), reporter).expect(WORLD_NEW_ERROR),
+            );
 
-                reason_for_stopping
-            } else {
-                let mut fuzzer = Fuzzer::new(
-                    test,
-                    mutator,
-                    serializer,
-                    sensor_and_pool,
-                    args.clone(),
-                    World::new(args.clone()).expect(WORLD_NEW_ERROR),
-                );
-                unsafe { fuzzer.state.set_up_signal_handler() };
+            let mut stats_headers = vec![CSVField::String("time".to_string())];
+            stats_headers.extend(fuzzer.state.fuzzer_stats.csv_headers());
+            stats_headers.extend(fuzzer.state.sensor_and_pool.stats().csv_headers());
+            fuzzer
+                .state
+                .world
+                .append_stats_file(&stats_headers)
+                .expect(WRITE_STATS_ERROR);
+            unsafe { fuzzer.state.set_up_signal_handler() };
 
-                let mut stats_headers = vec![CSVField::String("time".to_string())];
-                stats_headers.extend(fuzzer.state.fuzzer_stats.csv_headers());
-                stats_headers.extend(fuzzer.state.sensor_and_pool.stats().csv_headers());
-                fuzzer
-                    .state
-                    .world
-                    .append_stats_file(&stats_headers)
-                    .expect(WRITE_STATS_ERROR);
-                let reason_for_stopping = fuzzer.main_loop(false).unwrap_err();
-                fuzzer.state.write_stats().expect(WRITE_STATS_ERROR);
+            let reason_for_stopping = fuzzer.main_loop(false).unwrap_err();
+            fuzzer.state.write_stats().expect(WRITE_STATS_ERROR);
 
-                reason_for_stopping
-            }
+            reason_for_stopping
         }
         FuzzerCommand::MinifyInput { input_file } => {
-            let world = World::new(args.clone()).expect(WORLD_NEW_ERROR);
+            let world = World::new(args.clone(), reporter).expect(WORLD_NEW_ERROR);
             let value = world.read_input_file(input_file).expect(READ_INPUT_FILE_ERROR);
-            let value = serializer.from_data(&value).expect(SERIALIZER_FROM_DATA_ERROR);
-            if let Some(cache) = mutator.validate_value(&value) {
-                let mutation_step = mutator.default_mutation_step(&value, &cache);
-                args.max_input_cplx = mutator.complexity(&value, &cache) - 0.01;
-
-                let noop_sensor = NoopSensor;
-                let unit_pool = UnitPool::new(PoolStorageIndex(0));
-                let sensor_and_pool =
+            match decode_value(serializer.as_ref(), &mutator, &value) {
+                Ok((value, cache)) => {
+                    let mutation_step = mutator.default_mutation_step(&value, &cache);
+                    args.max_input_cplx = mutator.complexity(&value, &cache) - 0.01;
+
+                    let noop_sensor = NoopSensor;
+                    let unit_pool = UnitPool::new(PoolStorageIndex(0));
+                    let sensor_and_pool =
                     // 100:1 might seem like an excessive ratio, but the second pool will never make progress,
                     // therefore its relative weight willl diminish over time
                     // if after 100 iterations, the first pool makes progress, then the ratio will be 1:1
                     // what the exact value should be and how the ratio should evolve is an open question to me
                     AndSensorAndPool::new(sensor_and_pool, Box::new((noop_sensor, unit_pool)), 1.0, 100.0);
-                let mut fuzzer = Fuzzer::new(
-                    test,
-                    mutator,
-                    serializer,
-                    Box::new(sensor_and_pool),
-                    args.clone(),
-                    world,
-                );
-                fuzzer
-                    .state
-                    .pool_storage
-                    .insert(FuzzedInput::new(value, cache, mutation_step, 0), 1);
+                    let mut fuzzer = Fuzzer::new(
+                        test,
+                        mutator,
+                        serializer,
+                        Box::new(sensor_and_pool),
+                        args.clone(),
+                        world,
+                    );
+                    unsafe { fuzzer.state.set_up_signal_handler() };
 
-                unsafe { fuzzer.state.set_up_signal_handler() };
+                    // first look for a simpler failing test case with the mutator, and only fuzz for one
+                    // if it is already a local minimum
+                    if let Some((value, cplx, test_failure)) = fuzzer.simplify_failing_input(&value) {
+                        let FuzzerState {
+                            serializer,
+                            world,
+                            fuzzer_stats,
+                            ..
+                        } = &mut fuzzer.state;
+                        world
+                            .save_artifact(
+                                serializer.to_data(&value),
+                                cplx,
+                                ArtifactKind::Failure,
+                                serializer.extension(),
+                                Some(&test_failure),
+                                fuzzer_stats.total_number_of_runs,
+                            )
+                            .expect(SAVE_ARTIFACTS_ERROR);
+                        ReasonForStopping::TestFailure(value)
+                    } else {
+                        fuzzer
+                            .state
+                            .pool_storage
+                            .insert(FuzzedInput::new(value, cache, mutation_step, 0), 1);
 
-                fuzzer.main_loop(true).unwrap_err()
-            } else {
-                // TODO: send a better error message saying some inputs in the corpus cannot be read
-                // TODO: there should be an option to ignore invalid values
-                panic!("A value in the input corpus is invalid.");
+                        fuzzer.main_loop(true).unwrap_err()
+                    }
+                }
+                Err(reason) => panic!(
+                    "The input file {} is not a valid test case: {}",
+                    input_file.display(),
+                    reason
+                ),
             }
         }
+        FuzzerCommand::MinifyCorpus => {
+            assert!(
+                args.corpus_out.is_some() && args.corpus_out != args.corpus_in,
+                "the output corpus of the {} command must be given and be different from its input corpus",
+                COMMAND_MINIFY_CORPUS
+            );
+            let mut fuzzer = Fuzzer::new(
+                test,
+                mutator,
+                serializer,
+                with_test_failure_pool(sensor_and_pool, &args),
+                args.clone(),
+                World::new(args.clone(), reporter).expect(WORLD_NEW_ERROR),
+            );
+            unsafe { fuzzer.state.set_up_signal_handler() };
+
+            let result = fuzzer.minify_corpus();
+            fuzzer.state.write_stats().expect(WRITE_STATS_ERROR);
+            result.err().unwrap_or(ReasonForStopping::MinifiedCorpus)
+        }
+        FuzzerCommand::MergeCorpora { corpora } => {
+            assert!(
+                args.corpus_out.is_some(),
+                "the output corpus of the {} command must be given",
+                COMMAND_MERGE
+            );
+            let mut fuzzer = Fuzzer::new(
+                test,
+                mutator,
+                serializer,
+                with_test_failure_pool(sensor_and_pool, &args),
+                args.clone(),
+                World::new(args.clone(), reporter).expect(WORLD_NEW_ERROR),
+            );
+            unsafe { fuzzer.state.set_up_signal_handler() };
+
+            let result = fuzzer.merge_corpora(corpora);
+            fuzzer.state.write_stats().expect(WRITE_STATS_ERROR);
+            result.err().unwrap_or(ReasonForStopping::MergedCorpora)
+        }
+        FuzzerCommand::Replay { folders } => {
+            let mut fuzzer = Fuzzer::new(
+                test,
+                mutator,
+                serializer,
+                sensor_and_pool,
+                args.clone(),
+                World::new(args.clone(), reporter).expect(WORLD_NEW_ERROR),
+            );
+            unsafe { fuzzer.state.set_up_signal_handler() };
+
+            ReasonForStopping::Replayed(fuzzer.replay(folders))
+        }
         FuzzerCommand::Read { input_file } => {
             // no signal handlers are installed, but that should be ok as the exit code won't be 0
-            let mut world = World::new(args.clone()).expect(WORLD_NEW_ERROR);
+            let mut world = World::new(args.clone(), reporter).expect(WORLD_NEW_ERROR);
             let value = world.read_input_file(input_file).expect(READ_INPUT_FILE_ERROR);
-            let value = serializer.from_data(&value).expect(SERIALIZER_FROM_DATA_ERROR);
-            if let Some(cache) = mutator.validate_value(&value) {
-                let mutation_step = mutator.default_mutation_step(&value, &cache);
-                let input = FuzzedInput::new(value, cache, mutation_step, 0);
-                let cplx = input.complexity(&mutator);
-
-                let result = catch_unwind(AssertUnwindSafe(
-                    #[no_coverage]
-                    || (test)(input.value.borrow()),
-                ));
+            match decode_value(serializer.as_ref(), &mutator, &value) {
+                Ok((value, cache)) => {
+                    let mutation_step = mutator.default_mutation_step(&value, &cache);
+                    let input = FuzzedInput::new(value, cache, mutation_step, 0);
+                    let cplx = input.complexity(&mutator);
 
-                if result.is_err() || !result.unwrap() {
-                    world.report_event(FuzzerEvent::TestFailure, None);
-                    let content = serializer.to_data(&input.value);
-                    world
-                        .save_artifact(content, cplx, serializer.extension())
-                        .expect(SAVE_ARTIFACTS_ERROR);
-                    // in this case we really want to exit with a non-zero termination status here
-                    // because the Read command is only used by the input minify command from cargo-fuzzcheck
-                    // which checks that a crash happens by looking at the exit code
-                    // so we don't want to handle any error
-                    exit(TerminationStatus::TestFailure as i32);
-                } else {
-                    exit(TerminationStatus::Success as i32);
+                    // there is no signal handler for SIGALRM, so the process will be
+                    // terminated if the test case takes longer than the timeout
+                    if run_test(test.as_ref(), input.value.borrow(), args.timeout, args.backtrace_frames) {
+                        world.report_event(FuzzerEvent::TestFailure, None);
+                        let content = serializer.to_data(&input.value);
+                        let test_failure = unsafe { TEST_FAILURE.clone() };
+                        world
+                            .save_artifact(
+                                content,
+                                cplx,
+                                ArtifactKind::Failure,
+                                serializer.extension(),
+                                test_failure.as_ref(),
+                                1,
+                            )
+                            .expect(SAVE_ARTIFACTS_ERROR);
+                        // in this case we really want to exit with a non-zero termination status here
+                        // because the Read command is only used by the input minify command from cargo-fuzzcheck
+                        // which checks that a crash happens by looking at the exit code
+                        // so we don't want to handle any error
+                        exit(TerminationStatus::TestFailure as i32);
+                    } else {
+                        exit(TerminationStatus::Success as i32);
+                    }
                 }
-            } else {
-                // TODO: send a better error message saying some inputs in the corpus cannot be read
-                panic!("A value in the input corpus is invalid.");
+                Err(reason) => panic!(
+                    "The input file {} is not a valid test case: {}",
+                    input_file.display(),
+                    reason
+                ),
             }
         }
     };
@@ -641,3 +1798,52 @@ where
         reason_for_stopping,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{is_tested_crate_file, tested_crate_frames};
+    use std::path::Path;
+
+    #[test]
+    fn test_is_tested_crate_file() {
+        let current_dir = Path::new("/home/user/project");
+        assert!(is_tested_crate_file(Path::new("src/lib.rs"), current_dir));
+        assert!(is_tested_crate_file(
+            Path::new("/home/user/project/src/lib.rs"),
+            current_dir
+        ));
+        assert!(is_tested_crate_file(
+            Path::new("/home/user/project/tests/a.rs"),
+            current_dir
+        ));
+        assert!(!is_tested_crate_file(
+            Path::new("/home/user/other/src/lib.rs"),
+            current_dir
+        ));
+        assert!(!is_tested_crate_file(
+            Path::new("/rustc/library/std/src/panicking.rs"),
+            current_dir
+        ));
+        // fuzzcheck is not part of the tested crate, even when it is inside the current directory
+        let fuzzcheck_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let current_dir = fuzzcheck_dir.parent().unwrap();
+        assert!(!is_tested_crate_file(
+            &fuzzcheck_dir.join("src/builder.rs"),
+            current_dir
+        ));
+        assert!(!is_tested_crate_file(
+            &Path::new(file!()).with_file_name("builder.rs"),
+            current_dir
+        ));
+        assert!(is_tested_crate_file(
+            &fuzzcheck_dir.join("tests/fuzzer.rs"),
+            current_dir
+        ));
+    }
+
+    #[test]
+    fn test_tested_crate_frames_skip_fuzzcheck() {
+        // every frame of a unit test of fuzzcheck belongs to fuzzcheck or to the standard library
+        assert!(tested_crate_frames(8).is_empty());
+    }
+}
@@ -33,13 +33,18 @@
 pub extern crate fastrand;
 
 mod bitset;
+mod block_on;
 pub mod builder;
 mod code_coverage_sensor;
+mod comparisons_sensor;
 mod data_structures;
 mod fenwick_tree;
+mod fork_server;
 mod fuzzer;
 
+mod mutation_trace;
 pub mod mutators;
+pub mod reporters;
 pub mod sensors_and_pools;
 pub mod serializers;
 mod signals_handler;
@@ -48,7 +53,13 @@ mod traits;
 mod world;
 
 pub use fuzzcheck_common::arg::Arguments;
+pub use fuzzcheck_common::arg::CoverageTarget;
+pub use fuzzcheck_common::arg::EventStream;
+pub use fuzzcheck_common::arg::InvalidCorpusPolicy;
+pub use fuzzcheck_common::arg::NoProgressLimit;
+pub use fuzzcheck_common::{FuzzerEvent, FuzzerStats};
 
+pub(crate) use mutation_trace::MutationTrace;
 pub(crate) use split_string::split_string_by_whitespace;
 
 #[doc(inline)]
@@ -62,6 +73,8 @@ pub use crate::traits::Pool;
 #[doc(inline)]
 pub use crate::traits::PoolExt;
 #[doc(inline)]
+pub use crate::traits::Reporter;
+#[doc(inline)]
 pub use crate::traits::SaveToStatsFolder;
 #[doc(inline)]
 pub use crate::traits::Sensor;
@@ -78,6 +91,8 @@ pub use crate::fuzzer::PoolStorageIndex;
 #[doc(inline)]
 pub use fuzzer::ReasonForStopping;
 #[doc(inline)]
+pub use fuzzer::{ReplayOutcome, ReplayReport};
+#[doc(inline)]
 pub use mutators::DefaultMutator;
 #[doc(inline)]
 pub use traits::ExtendedMutator;
@@ -94,6 +109,10 @@ pub use traits::{CSVField, ToCSV};
 
 #[doc(inline)]
 pub use builder::fuzz_test;
+#[doc(inline)]
+pub use builder::{fuzz_test_differential, fuzz_test_differential_by};
+#[doc(inline)]
+pub use builder::{fuzz_test_model_based, fuzz_test_stateful, StateMachine};
 
 #[doc(inline)]
 pub use serializers::ByteSerializer;
@@ -227,6 +246,34 @@ pub use fuzzcheck_mutators_derive::DefaultMutator;
 */
 pub use fuzzcheck_mutators_derive::make_mutator;
 
+/**
+    Turn a test function into a fuzz test launched by `cargo fuzzcheck`.
+
+    The attribute generates a `#[test]` function of the same name, which fuzz-tests the annotated function
+    with [`fuzz_test`] and fails if a test failure was found. The fuzzer reads its arguments from
+    `cargo fuzzcheck`, but the following options can be given to the attribute:
+    * `mutator = <expr>`: the mutator of the test cases, instead of their [default mutator](DefaultMutator)
+    * `serializer = <expr>`: the serializer of the test cases, instead of a [`SerdeSerializer`]
+    * `max_cplx = <expr>`: the maximum complexity of the test cases, overriding `--max-cplx`
+    * `duration = <expr>`: the maximum duration of the fuzz test, in seconds, overriding `--stop-after-duration`
+
+    When the test is not launched by `cargo fuzzcheck`, such as with a plain `cargo test`, the test cases of
+    the corpus saved by `cargo fuzzcheck` are run instead, as a fast regression check
+    (see [`corpus_regression_check`](builder::FuzzerBuilder3::corpus_regression_check)).
+
+    ```no_run
+    # #![feature(no_coverage)]
+    # fn parse(bytes: &[u8]) {}
+    use fuzzcheck::DefaultMutator;
+
+    #[fuzzcheck::test(mutator = Vec::<u8>::default_mutator(), max_cplx = 1024)]
+    fn parse_does_not_panic(bytes: &Vec<u8>) {
+        parse(bytes);
+    }
+    ```
+*/
+pub use fuzzcheck_mutators_derive::test;
+
 /**
  * A struct that stores the value, cache, and mutation step of an input.
  * It is used for convenience.
@@ -235,7 +282,11 @@ pub(crate) struct FuzzedInput<T: Clone, Mut: Mutator<T>> {
     pub value: T,
     pub cache: Mut::Cache,
     pub mutation_step: Mut::MutationStep,
+    /// The number of times `mutation_step` was given to the mutator, which is saved in the checkpoints
+    /// so that the mutation step can be restored when the fuzzing session is resumed
+    pub mutations: usize,
     pub generation: usize,
+    pub trace: MutationTrace,
 }
 impl<T: Clone, Mut: Mutator<T>> Clone for FuzzedInput<T, Mut> {
     fn clone(&self) -> Self {
@@ -243,7 +294,9 @@ impl<T: Clone, Mut: Mutator<T>> Clone for FuzzedInput<T, Mut> {
             value: self.value.clone(),
             cache: self.cache.clone(),
             mutation_step: self.mutation_step.clone(),
+            mutations: self.mutations,
             generation: self.generation,
+            trace: self.trace.clone(),
         }
     }
 }
@@ -255,7 +308,9 @@ impl<T: Clone, Mut: Mutator<T>> FuzzedInput<T, Mut> {
             value,
             cache,
             mutation_step,
+            mutations: 0,
             generation,
+            trace: MutationTrace::default(),
         }
     }
 
@@ -273,9 +328,21 @@ impl<T: Clone, Mut: Mutator<T>> FuzzedInput<T, Mut> {
 
     #[no_coverage]
     pub fn mutate(&mut self, m: &mut Mut, max_cplx: f64) -> Option<(Mut::UnmutateToken, f64)> {
+        self.mutations += 1;
         m.ordered_mutate(&mut self.value, &mut self.cache, &mut self.mutation_step, max_cplx)
     }
 
+    #[no_coverage]
+    pub fn crossover(
+        &mut self,
+        m: &mut Mut,
+        other: &T,
+        other_cache: &Mut::Cache,
+        max_cplx: f64,
+    ) -> Option<(Mut::UnmutateToken, f64)> {
+        m.crossover_mutate(&mut self.value, &mut self.cache, other, other_cache, max_cplx)
+    }
+
     #[no_coverage]
     pub fn unmutate(&mut self, m: &Mut, t: Mut::UnmutateToken) {
         m.unmutate(&mut self.value, &mut self.cache, t);
@@ -0,0 +1,143 @@
+use std::fmt;
+use std::rc::Rc;
+
+/**
+The chain of mutations that produced a test case, from the first test case of its lineage to the test case itself.
+
+Each mutation is described by [`Mutator::describe_mutation`](crate::Mutator::describe_mutation) and is associated
+with the name of the corpus entry it was applied to. The traces of the test cases of the pool share their common
+mutations, so that cloning or extending a trace is cheap.
+*/
+#[derive(Clone, Default)]
+pub(crate) struct MutationTrace {
+    /// The name of the corpus entry holding the test case, if it was added to the corpus
+    name: Option<Rc<str>>,
+    last_step: Option<Rc<MutationTraceStep>>,
+}
+
+struct MutationTraceStep {
+    previous: Option<Rc<MutationTraceStep>>,
+    /// The name of the corpus entry that was mutated
+    parent: Option<Rc<str>>,
+    /// The name of the corpus entry that the parent was combined with, if the mutation is a crossover
+    crossover_with: Option<Rc<str>>,
+    mutation: String,
+}
+
+impl MutationTrace {
+    /// The trace of the test case obtained by applying the given mutation to the test case of `self`.
+    ///
+    /// If the mutation is a crossover, `crossover_with` is the trace of the other test case.
+    #[no_coverage]
+    pub fn with_mutation(&self, mutation: String, crossover_with: Option<&MutationTrace>) -> Self {
+        Self {
+            name: None,
+            last_step: Some(Rc::new(MutationTraceStep {
+                previous: self.last_step.clone(),
+                parent: self.name.clone(),
+                crossover_with: crossover_with.and_then(
+                    #[no_coverage]
+                    |other| other.name.clone(),
+                ),
+                mutation,
+            })),
+        }
+    }
+
+    /// The same trace, for a test case that was added to the corpus with the given name
+    #[no_coverage]
+    pub fn with_name(&self, name: &str) -> Self {
+        Self {
+            name: Some(Rc::from(name)),
+            last_step: self.last_step.clone(),
+        }
+    }
+}
+
+/// One line per mutation, starting with the oldest one, in the form `<parent>: <mutation>`
+impl fmt::Display for MutationTrace {
+    #[no_coverage]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut steps = vec![];
+        let mut step = self.last_step.as_ref();
+        while let Some(s) = step {
+            steps.push(s);
+            step = s.previous.as_ref();
+        }
+        for step in steps.into_iter().rev() {
+            write!(
+                f,
+                "{}: {}",
+                step.parent.as_deref().unwrap_or("<unsaved test case>"),
+                step.mutation
+            )?;
+            if let Some(other) = &step.crossover_with {
+                write!(f, " (crossover with {})", other)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MutationTrace;
+    use crate::reporters::QuietReporter;
+    use crate::world::World;
+    use crate::Arguments;
+    use fuzzcheck_common::arg::options_parser;
+
+    #[no_coverage]
+    fn example_trace() -> MutationTrace {
+        let a = MutationTrace::default()
+            .with_mutation("replace with an arbitrary vector of length 2".to_owned(), None)
+            .with_name("a");
+        let b = a.with_mutation("element 0: 3 -> 4".to_owned(), None).with_name("b");
+        let c = a.with_mutation("remove the element at index 1".to_owned(), None);
+        b.with_mutation("insert an element at index 0".to_owned(), Some(&c))
+    }
+
+    #[test]
+    fn test_display_mutation_trace() {
+        assert_eq!(MutationTrace::default().to_string(), "");
+        assert_eq!(
+            example_trace().to_string(),
+            "<unsaved test case>: replace with an arbitrary vector of length 2\n\
+             a: element 0: 3 -> 4\n\
+             b: insert an element at index 0\n"
+        );
+        let other = MutationTrace::default().with_name("c");
+        assert_eq!(
+            example_trace()
+                .with_name("d")
+                .with_mutation("swap elements 0 and 1".to_owned(), Some(&other))
+                .to_string(),
+            "<unsaved test case>: replace with an arbitrary vector of length 2\n\
+             a: element 0: 3 -> 4\n\
+             b: insert an element at index 0\n\
+             d: swap elements 0 and 1 (crossover with c)\n"
+        );
+    }
+
+    #[test]
+    fn test_save_mutation_trace() {
+        let folder = std::env::temp_dir().join(format!("fuzzcheck-mutation-trace-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        let matches = options_parser().parse(["--stats", folder.to_str().unwrap()]).unwrap();
+        let settings = Arguments::from_matches(&matches, false).unwrap();
+        let world = World::new(settings, Box::new(QuietReporter)).unwrap();
+        assert!(world.saves_mutation_traces());
+
+        let trace = example_trace().with_name("d");
+        world.save_mutation_trace("d", &trace).unwrap();
+        let path = world
+            .stats_folder
+            .as_ref()
+            .unwrap()
+            .join("mutation_traces")
+            .join("d.txt");
+        assert_eq!(std::fs::read_to_string(path).unwrap(), trace.to_string());
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+}
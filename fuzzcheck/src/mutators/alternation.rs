@@ -446,4 +446,67 @@ where
             result
         }
     }
+
+    /// First switch to a minimal value of each alternative that can be simpler than the current one, then
+    /// simplify the value within the alternatives it belongs to
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &T, cache: &Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {
+        let cplx = self.mutators[cache[0].mutator_idx].complexity(value, &cache[0].inner);
+        for (idx, other) in self.mutators.iter().enumerate() {
+            if cache.iter().all(
+                #[no_coverage]
+                |c| c.mutator_idx != idx,
+            ) && other.min_complexity() < cplx
+            {
+                // the first value generated by the alternative is one of its simplest values
+                let mut step = other.default_arbitrary_step();
+                if let Some((simpler, simpler_cplx)) = other.ordered_arbitrary(&mut step, other.min_complexity()) {
+                    if simpler_cplx < cplx && candidate(simpler) {
+                        return true;
+                    }
+                }
+            }
+        }
+        cache.iter().any(
+            #[no_coverage]
+            |c| self.mutators[c.mutator_idx].ordered_simplify(value, &c.inner, candidate),
+        )
+    }
+
+    /// Cross over the two values using an alternative that is shared by both of them, if any
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut T,
+        cache: &mut Self::Cache,
+        other: &T,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        for c in cache.iter_mut() {
+            let idx = c.mutator_idx;
+            if let Some(other_c) = other_cache.iter().find(
+                #[no_coverage]
+                |oc| oc.mutator_idx == idx,
+            ) {
+                let mutator = &self.mutators[idx];
+                if let Some((t, cplx)) = mutator.crossover_mutate(value, &mut c.inner, other, &other_c.inner, max_cplx)
+                {
+                    return Some((UnmutateToken::Inner(idx, t), self.complexity_from_inner(cplx)));
+                }
+            }
+        }
+        None
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &T, token: &Self::UnmutateToken) -> String {
+        match token {
+            UnmutateToken::Replace(_) => "replace with an arbitrary value".to_string(),
+            UnmutateToken::Inner(idx, t) => self.mutators[*idx].describe_mutation(value, t),
+        }
+    }
 }
@@ -130,6 +130,41 @@ impl<T: Clone, M: Mutator<T>> Mutator<Arc<T>> for ArcMutator<M> {
     {
         self.mutator.recursing_part::<V, N>(parent, value, index)
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &Arc<T>, cache: &Self::Cache, candidate: &mut dyn FnMut(Arc<T>) -> bool) -> bool {
+        self.mutator.ordered_simplify(
+            value,
+            cache,
+            &mut #[no_coverage]
+            |simpler| candidate(Arc::new(simpler)),
+        )
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut Arc<T>,
+        cache: &mut Self::Cache,
+        other: &Arc<T>,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        let mut v = value.as_ref().clone();
+        let res = self
+            .mutator
+            .crossover_mutate(&mut v, cache, other, other_cache, max_cplx);
+        *value = Arc::new(v);
+        res
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &Arc<T>, token: &Self::UnmutateToken) -> String {
+        self.mutator.describe_mutation(value, token)
+    }
 }
 
 impl<T> DefaultMutator for Arc<T>
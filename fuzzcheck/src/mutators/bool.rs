@@ -148,4 +148,32 @@ impl Mutator<bool> for BoolMutator {
     {
         None
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &bool, _cache: &Self::Cache, candidate: &mut dyn FnMut(bool) -> bool) -> bool {
+        *value && candidate(false)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut bool,
+        _cache: &mut Self::Cache,
+        other: &bool,
+        _other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        if max_cplx < self.min_complexity() || value == other {
+            return None;
+        }
+        Some((std::mem::replace(value, *other), BOOL_COMPLEXITY))
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &bool, token: &Self::UnmutateToken) -> String {
+        format!("{} -> {}", token, value)
+    }
 }
@@ -120,6 +120,37 @@ impl<T: Clone, M: Mutator<T>> Mutator<Box<T>> for BoxMutator<M> {
     {
         self.mutator.recursing_part::<V, N>(parent, value, index)
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &Box<T>, cache: &Self::Cache, candidate: &mut dyn FnMut(Box<T>) -> bool) -> bool {
+        self.mutator.ordered_simplify(
+            value,
+            cache,
+            &mut #[no_coverage]
+            |simpler| candidate(Box::new(simpler)),
+        )
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut Box<T>,
+        cache: &mut Self::Cache,
+        other: &Box<T>,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        self.mutator
+            .crossover_mutate(value, cache, other, other_cache, max_cplx)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &Box<T>, token: &Self::UnmutateToken) -> String {
+        self.mutator.describe_mutation(value, token)
+    }
 }
 
 impl<T> DefaultMutator for Box<T>
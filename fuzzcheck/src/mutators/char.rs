@@ -198,4 +198,27 @@ impl Mutator<char> for CharWithinRangeMutator {
     {
         None
     }
+
+    /// Replace the value by the other one
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut char,
+        _cache: &mut Self::Cache,
+        other: &char,
+        _other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        if max_cplx < self.min_complexity() || value == other {
+            return None;
+        }
+        Some((std::mem::replace(value, *other), self.cplx))
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &char, token: &Self::UnmutateToken) -> String {
+        format!("{:?} -> {:?}", token, value)
+    }
 }
@@ -189,4 +189,51 @@ where
             _ => unreachable!(),
         }
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &T, cache: &Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {
+        match (self, cache) {
+            (Either::Left(m), Either::Left(c)) => m.ordered_simplify(value, c, candidate),
+            (Either::Right(m), Either::Right(c)) => m.ordered_simplify(value, c, candidate),
+            _ => unreachable!(),
+        }
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut T,
+        cache: &mut Self::Cache,
+        other: &T,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        match (self, cache, other_cache) {
+            (Either::Left(m), Either::Left(c), Either::Left(oc)) => {
+                m.crossover_mutate(value, c, other, oc, max_cplx).map(
+                    #[no_coverage]
+                    |(t, cplx)| (Either::Left(t), cplx),
+                )
+            }
+            (Either::Right(m), Either::Right(c), Either::Right(oc)) => {
+                m.crossover_mutate(value, c, other, oc, max_cplx).map(
+                    #[no_coverage]
+                    |(t, cplx)| (Either::Right(t), cplx),
+                )
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &T, token: &Self::UnmutateToken) -> String {
+        match (self, token) {
+            (Either::Left(m), Either::Left(t)) => m.describe_mutation(value, t),
+            (Either::Right(m), Either::Right(t)) => m.describe_mutation(value, t),
+            _ => unreachable!(),
+        }
+    }
 }
@@ -156,4 +156,37 @@ where
     {
         None
     }
+
+    /// The first variant of the enum
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &T, _cache: &Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {
+        value.get_item_index() != 0 && candidate(T::from_item_index(0))
+    }
+
+    /// Replace the value by the other one
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut T,
+        _cache: &mut Self::Cache,
+        other: &T,
+        _other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        let old_index = value.get_item_index();
+        let new_index = other.get_item_index();
+        if max_cplx < <Self as Mutator<T>>::min_complexity(self) || old_index == new_index {
+            return None;
+        }
+        *value = T::from_item_index(new_index);
+        Some((old_index, self.cplx))
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &T, token: &Self::UnmutateToken) -> String {
+        format!("variant {} -> variant {}", token, value.get_item_index())
+    }
 }
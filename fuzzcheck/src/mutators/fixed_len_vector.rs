@@ -394,6 +394,92 @@ impl<T: Clone + 'static, M: Mutator<T>> Mutator<Vec<T>> for FixedLenVecMutator<T
             result
         }
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &Vec<T>, cache: &Self::Cache, candidate: &mut dyn FnMut(Vec<T>) -> bool) -> bool {
+        for (i, ((el, el_cache), mutator)) in value
+            .iter()
+            .zip(cache.inner.iter())
+            .zip(self.mutators.iter())
+            .enumerate()
+        {
+            let accepted = mutator.ordered_simplify(
+                el,
+                el_cache,
+                &mut #[no_coverage]
+                |simpler_el| {
+                    let mut simpler = value.clone();
+                    simpler[i] = simpler_el;
+                    candidate(simpler)
+                },
+            );
+            if accepted {
+                return true;
+            }
+        }
+        false
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut Vec<T>,
+        cache: &mut Self::Cache,
+        other: &Vec<T>,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        if value.is_empty() {
+            return None;
+        }
+        let current_cplx = self.complexity(value, cache);
+        let idx = self.rng.usize(..value.len());
+        let mutator = &self.mutators[idx];
+        let el_cache = &mut cache.inner[idx];
+        let old_cplx = mutator.complexity(&value[idx], el_cache);
+        let (token, new_cplx) = mutator.crossover_mutate(
+            &mut value[idx],
+            el_cache,
+            &other[idx],
+            &other_cache.inner[idx],
+            max_cplx - current_cplx + old_cplx,
+        )?;
+        Some((
+            UnmutateVecToken::Element(idx, token),
+            current_cplx - old_cplx + new_cplx,
+        ))
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &Vec<T>, token: &Self::UnmutateToken) -> String {
+        match token {
+            UnmutateVecToken::Element(idx, t) => {
+                format!(
+                    "element {}: {}",
+                    idx,
+                    self.mutators[*idx].describe_mutation(&value[*idx], t)
+                )
+            }
+            UnmutateVecToken::Elements(tokens) => tokens
+                .iter()
+                .map(
+                    #[no_coverage]
+                    |(idx, t)| {
+                        format!(
+                            "element {}: {}",
+                            idx,
+                            self.mutators[*idx].describe_mutation(&value[*idx], t)
+                        )
+                    },
+                )
+                .collect::<Vec<_>>()
+                .join(", "),
+            UnmutateVecToken::Replace(_) => "replace with an arbitrary vector".to_string(),
+        }
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -189,6 +189,34 @@ impl Mutator<AST> for ASTMutator {
     {
         self.inner.recursing_part::<T, M>(parent, value, &mut index.inner)
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &AST, cache: &Self::Cache, candidate: &mut dyn FnMut(AST) -> bool) -> bool {
+        self.inner.ordered_simplify(value, &cache.inner, candidate)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut AST,
+        cache: &mut Self::Cache,
+        other: &AST,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        let (token, cplx) =
+            self.inner
+                .crossover_mutate(value, &mut cache.inner, other, &other_cache.inner, max_cplx)?;
+        Some((Self::UnmutateToken::new(token), cplx))
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &AST, token: &Self::UnmutateToken) -> String {
+        self.inner.describe_mutation(value, &token.inner)
+    }
 }
 
 // /// A mutator created by [`grammar_based_string_mutator`](crate::mutators::grammar::grammar_based_string_mutator)
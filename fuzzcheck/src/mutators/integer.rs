@@ -1,3 +1,4 @@
+use crate::comparisons_sensor::{compared_operand_of_size, has_compared_operands};
 use crate::DefaultMutator;
 use crate::Mutator;
 
@@ -9,6 +10,8 @@ use crate::Mutator;
     * also produce values close to the original integer first. So mutating 100 will first produce numbers
       such as 101, 99, 102, 98, etc.
     * be very fast
+    * from time to time, produce one of the operands of the comparisons observed by the ComparisonsSensor,
+      if there is one of the same size. It is what allows the fuzzer to guess magic numbers.
 
     One idea to create arbitrary integers that don't repeat themselves and span the whole search space was
     to use a binary-search-like approach, as written in the function binary_search_arbitrary. However that
@@ -147,6 +150,21 @@ macro_rules! impl_int_mutator {
 
                 result
             }
+
+            /// An operand of the comparisons observed by the `ComparisonsSensor`, with a probability of 1/8
+            ///
+            /// The random number generator is not used if no comparison was observed, so that the mutator
+            /// behaves exactly the same when the sensor is not used.
+            #[no_coverage]
+            fn compared_operand(&self) -> Option<$name> {
+                if !has_compared_operands() || self.rng.u8(..8) != 0 {
+                    return None;
+                }
+                compared_operand_of_size(&self.rng, std::mem::size_of::<$name>()).map(
+                    #[no_coverage]
+                    |operand| operand as $name,
+                )
+            }
         }
 
         impl Mutator<$name> for $name_mutator {
@@ -207,7 +225,10 @@ macro_rules! impl_int_mutator {
             #[doc(hidden)]
             #[no_coverage]
             fn random_arbitrary(&self, _max_cplx: f64) -> ($name, f64) {
-                let value = self.rng.$name(..);
+                let value = self.compared_operand().unwrap_or_else(
+                    #[no_coverage]
+                    || self.rng.$name(..),
+                );
                 (value, <$name>::BITS as f64)
             }
             #[doc(hidden)]
@@ -225,6 +246,10 @@ macro_rules! impl_int_mutator {
                 if *step > 10u64.saturating_add(<$name>::MAX as u64) {
                     return None;
                 }
+                // does not count as a step, so that the permutation of all values is still explored
+                if let Some(operand) = self.compared_operand() {
+                    return Some((std::mem::replace(value, operand), <$name>::BITS as f64));
+                }
                 let token = *value;
                 *value = {
                     let mut tmp_step = *step;
@@ -252,7 +277,11 @@ macro_rules! impl_int_mutator {
                 _cache: &mut Self::Cache,
                 _max_cplx: f64,
             ) -> (Self::UnmutateToken, f64) {
-                (std::mem::replace(value, self.rng.$name(..)), <$name>::BITS as f64)
+                let new_value = self.compared_operand().unwrap_or_else(
+                    #[no_coverage]
+                    || self.rng.$name(..),
+                );
+                (std::mem::replace(value, new_value), <$name>::BITS as f64)
             }
             #[doc(hidden)]
             #[no_coverage]
@@ -279,6 +308,48 @@ macro_rules! impl_int_mutator {
             {
                 None
             }
+
+            /// Zero, then half of the value, then the value moved one step closer to zero
+            #[doc(hidden)]
+            #[no_coverage]
+            fn ordered_simplify(
+                &self,
+                value: &$name,
+                _cache: &Self::Cache,
+                candidate: &mut dyn FnMut($name) -> bool,
+            ) -> bool {
+                let value = *value;
+                if value == 0 {
+                    return false;
+                }
+                let half = value / 2;
+                #[allow(unused_comparisons)]
+                let closer = if value < 0 { value + 1 } else { value - 1 };
+                candidate(0) || (half != 0 && candidate(half)) || (closer != 0 && closer != half && candidate(closer))
+            }
+
+            /// Replace the value by the other one
+            #[doc(hidden)]
+            #[no_coverage]
+            fn crossover_mutate(
+                &self,
+                value: &mut $name,
+                _cache: &mut Self::Cache,
+                other: &$name,
+                _other_cache: &Self::Cache,
+                max_cplx: f64,
+            ) -> Option<(Self::UnmutateToken, f64)> {
+                if max_cplx < self.min_complexity() || value == other {
+                    return None;
+                }
+                Some((std::mem::replace(value, *other), <$name>::BITS as f64))
+            }
+
+            #[doc(hidden)]
+            #[no_coverage]
+            fn describe_mutation(&self, value: &$name, token: &Self::UnmutateToken) -> String {
+                format!("{} -> {}", token, value)
+            }
         }
 
         impl DefaultMutator for $name {
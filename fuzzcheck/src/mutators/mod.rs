@@ -224,6 +224,82 @@ pub mod testing_utilities {
             }
         }
     }
+    /// Simplify the value with the mutator’s `ordered_simplify` method until none of its candidates
+    /// satisfy the predicate, checking that every candidate is valid and not more complex than the value
+    #[no_coverage]
+    pub fn simplify<T, M>(m: &M, mut value: T, predicate: impl Fn(&T) -> bool) -> T
+    where
+        M: Mutator<T>,
+        T: Clone + Debug,
+    {
+        loop {
+            let cache = m.validate_value(&value).unwrap();
+            let cplx = m.complexity(&value, &cache);
+            let mut simpler = None;
+            m.ordered_simplify(
+                &value,
+                &cache,
+                &mut #[no_coverage]
+                |candidate| {
+                    let candidate_cache = m.validate_value(&candidate).unwrap();
+                    let candidate_cplx = m.complexity(&candidate, &candidate_cache);
+                    assert!(
+                        candidate_cplx <= cplx,
+                        "{:?} is more complex than {:?}",
+                        candidate,
+                        value
+                    );
+                    if predicate(&candidate) {
+                        simpler = Some(candidate);
+                        true
+                    } else {
+                        false
+                    }
+                },
+            );
+            if let Some(simpler) = simpler {
+                value = simpler;
+            } else {
+                return value;
+            }
+        }
+    }
+    /// Cross over random pairs of arbitrary values, checking that the complexity returned by `crossover_mutate`
+    /// is consistent and that `unmutate` restores the original value
+    ///
+    /// Returns the number of successful crossovers.
+    #[no_coverage]
+    pub fn test_crossover<T, M>(m: &M, maximum_complexity: f64, nbr_crossovers: usize) -> usize
+    where
+        M: Mutator<T>,
+        T: Clone + Debug + PartialEq,
+    {
+        let mut nbr_successes = 0;
+        for _i in 0..nbr_crossovers {
+            let (x, _) = m.random_arbitrary(maximum_complexity);
+            let (other, _) = m.random_arbitrary(maximum_complexity);
+            let mut cache = m.validate_value(&x).unwrap();
+            let other_cache = m.validate_value(&other).unwrap();
+            let mut x_mut = x.clone();
+            if let Some((token, cplx)) =
+                m.crossover_mutate(&mut x_mut, &mut cache, &other, &other_cache, maximum_complexity)
+            {
+                nbr_successes += 1;
+                let validated = m.validate_value(&x_mut).unwrap();
+                let other_cplx = m.complexity(&x_mut, &validated);
+                assert!(
+                    (cplx - other_cplx).abs() < 0.01,
+                    "{:.3} != {:.3} for {:?}",
+                    cplx,
+                    other_cplx,
+                    x_mut
+                );
+                m.unmutate(&mut x_mut, &mut cache, token);
+                assert_eq!(x, x_mut);
+            }
+        }
+        nbr_successes
+    }
     #[no_coverage]
     pub fn bench_mutator<T, M>(
         m: M,
@@ -48,4 +48,12 @@ where
     M: Mutator<Value>,
 {
     fn revert(self, mutator: &M, value: &mut Value, cache: &mut M::Cache);
+    /// Describe the mutation that is reverted by `self`, given the mutated value
+    ///
+    /// By default, it returns `"unknown mutation"`.
+    #[no_coverage]
+    fn describe(&self, mutator: &M, value: &Value) -> String {
+        let _ = (mutator, value);
+        "unknown mutation".to_owned()
+    }
 }
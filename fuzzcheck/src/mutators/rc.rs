@@ -128,6 +128,41 @@ impl<T: Clone, M: Mutator<T>> Mutator<Rc<T>> for RcMutator<M> {
     {
         self.mutator.recursing_part::<V, N>(parent, value, index)
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &Rc<T>, cache: &Self::Cache, candidate: &mut dyn FnMut(Rc<T>) -> bool) -> bool {
+        self.mutator.ordered_simplify(
+            value,
+            cache,
+            &mut #[no_coverage]
+            |simpler| candidate(Rc::new(simpler)),
+        )
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut Rc<T>,
+        cache: &mut Self::Cache,
+        other: &Rc<T>,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        let mut v = value.as_ref().clone();
+        let res = self
+            .mutator
+            .crossover_mutate(&mut v, cache, other, other_cache, max_cplx);
+        *value = Rc::new(v);
+        res
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &Rc<T>, token: &Self::UnmutateToken) -> String {
+        self.mutator.describe_mutation(value, token)
+    }
 }
 
 impl<T> DefaultMutator for Rc<T>
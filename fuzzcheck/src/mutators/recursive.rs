@@ -277,6 +277,36 @@ where
             }
         }
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &T, cache: &Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {
+        self.reference
+            .upgrade()
+            .unwrap()
+            .ordered_simplify(value, cache, candidate)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut T,
+        cache: &mut Self::Cache,
+        other: &T,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        self.reference
+            .upgrade()
+            .unwrap()
+            .crossover_mutate(value, cache, other, other_cache, max_cplx)
+    }
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &T, token: &Self::UnmutateToken) -> String {
+        self.reference.upgrade().unwrap().describe_mutation(value, token)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -439,4 +469,61 @@ where
     {
         self.mutator.recursing_part::<V, N>(parent, value, index)
     }
+
+    /// First replace the value by one of its recursing parts, then simplify it with the inner mutator
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &T, cache: &Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {
+        let mut recursing_part_index = self.default_recursing_part_index(value, cache);
+        while let Some(part) = self
+            .mutator
+            .recursing_part::<T, Self>(self, value, &mut recursing_part_index)
+        {
+            if candidate(part.clone()) {
+                return true;
+            }
+        }
+        self.mutator.ordered_simplify(value, cache, candidate)
+    }
+
+    /// Either replace the value by one of the recursing parts of `other`, or cross them over with the inner mutator
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut T,
+        cache: &mut Self::Cache,
+        other: &T,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        if self.rng.bool() {
+            let mut recursing_part_index = self.default_recursing_part_index(other, other_cache);
+            if let Some(part) = self
+                .mutator
+                .recursing_part::<T, Self>(self, other, &mut recursing_part_index)
+            {
+                let mut new = part.clone();
+                let new_cache = self.validate_value(&new).unwrap();
+                let cplx = self.complexity(&new, &new_cache);
+                if cplx <= max_cplx {
+                    std::mem::swap(value, &mut new);
+                    let token = RecursiveMutatorUnmutateToken::Replace(new);
+                    return Some((token, cplx));
+                }
+            }
+        }
+        let (token, cplx) = self
+            .mutator
+            .crossover_mutate(value, cache, other, other_cache, max_cplx)?;
+        Some((RecursiveMutatorUnmutateToken::Token(token), cplx))
+    }
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &T, token: &Self::UnmutateToken) -> String {
+        match token {
+            RecursiveMutatorUnmutateToken::Replace(_) => "replace with a recursive part".to_string(),
+            RecursiveMutatorUnmutateToken::Token(t) => self.mutator.describe_mutation(value, t),
+        }
+    }
 }
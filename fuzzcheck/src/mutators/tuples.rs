@@ -178,6 +178,36 @@ where
     where
         V: Clone + 'static,
         N: Mutator<V>;
+
+    #[no_coverage]
+    fn ordered_simplify<'a>(
+        &self,
+        value: TupleKind::Ref<'a>,
+        cache: &'a Self::Cache,
+        candidate: &mut dyn FnMut(T) -> bool,
+    ) -> bool {
+        let _ = (value, cache, candidate);
+        false
+    }
+
+    #[no_coverage]
+    fn crossover_mutate<'a>(
+        &self,
+        value: TupleKind::Mut<'a>,
+        cache: &'a mut Self::Cache,
+        other: TupleKind::Ref<'a>,
+        other_cache: &'a Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        let _ = (value, cache, other, other_cache, max_cplx);
+        None
+    }
+
+    #[no_coverage]
+    fn describe_mutation<'a>(&self, value: TupleKind::Ref<'a>, token: &Self::UnmutateToken) -> String {
+        let _ = (value, token);
+        String::from("unknown mutation")
+    }
 }
 
 /// A wrapper that transforms a [`TupleMutator`] into a [`Mutator`] of values [with a tuple structure](TupleStructure).
@@ -319,6 +349,32 @@ where
     {
         self.mutator.recursing_part::<V, N>(parent, value.get_ref(), index)
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &T, cache: &Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {
+        self.mutator.ordered_simplify(value.get_ref(), cache, candidate)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut T,
+        cache: &mut Self::Cache,
+        other: &T,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        self.mutator
+            .crossover_mutate(value.get_mut(), cache, other.get_ref(), other_cache, max_cplx)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &T, token: &Self::UnmutateToken) -> String {
+        self.mutator.describe_mutation(value.get_ref(), token)
+    }
 }
 
 pub use tuple0::{Tuple0, Tuple0Mutator};
@@ -624,6 +680,43 @@ mod tuple1 {
         {
             self.mutator_0.recursing_part::<___V, ___N>(parent, value.0, index)
         }
+        #[doc(hidden)]
+        #[no_coverage]
+        fn ordered_simplify<'a>(
+            &self,
+            value: <Tuple1<T0> as RefTypes>::Ref<'a>,
+            cache: &'a Self::Cache,
+            candidate: &mut dyn FnMut(T) -> bool,
+        ) -> bool {
+            self.mutator_0.ordered_simplify(
+                value.0,
+                cache,
+                &mut #[no_coverage]
+                |simpler| candidate(T::new((simpler,))),
+            )
+        }
+        #[doc(hidden)]
+        #[no_coverage]
+        fn crossover_mutate<'a>(
+            &self,
+            value: <Tuple1<T0> as RefTypes>::Mut<'a>,
+            cache: &'a mut Self::Cache,
+            other: <Tuple1<T0> as RefTypes>::Ref<'a>,
+            other_cache: &'a Self::Cache,
+            max_cplx: f64,
+        ) -> Option<(Self::UnmutateToken, f64)> {
+            self.mutator_0
+                .crossover_mutate(value.0, cache, other.0, other_cache, max_cplx)
+        }
+        #[doc(hidden)]
+        #[no_coverage]
+        fn describe_mutation<'a>(
+            &self,
+            value: <Tuple1<T0> as RefTypes>::Ref<'a>,
+            token: &Self::UnmutateToken,
+        ) -> String {
+            self.mutator_0.describe_mutation(value.0, token)
+        }
     }
     impl<T0> crate::mutators::DefaultMutator for (T0,)
     where
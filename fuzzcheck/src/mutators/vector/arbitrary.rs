@@ -29,6 +29,11 @@ where
     ) {
         std::mem::swap(value, &mut self.value);
     }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, value: &Vec<T>) -> String {
+        format!("replace with an arbitrary vector of length {}", value.len())
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for Arbitrary
@@ -0,0 +1,161 @@
+use std::any::{Any, TypeId};
+use std::ops::Range;
+
+use super::insert_many_elements::insert_many;
+use super::VecMutator;
+use crate::comparisons_sensor::{compared_operand, has_compared_operands};
+use crate::mutators::mutations::{Mutation, RevertMutation};
+use crate::Mutator;
+
+/// Insert the bytes of one of the operands of the comparisons observed by the
+/// [`ComparisonsSensor`](crate::sensors_and_pools::ComparisonsSensor), in little
+/// or big endian order.
+///
+/// It only applies to vectors of bytes.
+pub struct InsertComparedBytes;
+
+#[derive(Clone)]
+pub struct InsertComparedBytesStep;
+
+pub struct ConcreteInsertComparedBytes<T> {
+    els: Vec<T>,
+    added_cplx: f64,
+    idx: usize,
+}
+pub struct RevertInsertComparedBytes {
+    idcs: Range<usize>,
+}
+
+impl<T, M> RevertMutation<Vec<T>, VecMutator<T, M>> for RevertInsertComparedBytes
+where
+    T: Clone + 'static,
+    M: Mutator<T>,
+{
+    #[no_coverage]
+    fn revert(
+        self,
+        _mutator: &VecMutator<T, M>,
+        value: &mut Vec<T>,
+        _cache: &mut <VecMutator<T, M> as Mutator<Vec<T>>>::Cache,
+    ) {
+        let _ = value.drain(self.idcs);
+    }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, _value: &Vec<T>) -> String {
+        format!(
+            "insert {} bytes compared by the test function at index {}",
+            self.idcs.len(),
+            self.idcs.start
+        )
+    }
+}
+
+impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for InsertComparedBytes
+where
+    T: Clone + 'static,
+    M: Mutator<T>,
+{
+    type RandomStep = InsertComparedBytesStep;
+    type Step = InsertComparedBytesStep;
+    type Concrete<'a> = ConcreteInsertComparedBytes<T>;
+    type Revert = RevertInsertComparedBytes;
+
+    #[no_coverage]
+    fn default_random_step(&self, mutator: &VecMutator<T, M>, value: &Vec<T>) -> Option<Self::RandomStep> {
+        if TypeId::of::<T>() != TypeId::of::<u8>() || mutator.m.max_complexity() == 0. || !has_compared_operands() {
+            return None;
+        }
+        if value.len() >= *mutator.len_range.end() {
+            None
+        } else {
+            Some(InsertComparedBytesStep)
+        }
+    }
+
+    #[no_coverage]
+    fn random<'a>(
+        mutator: &VecMutator<T, M>,
+        value: &Vec<T>,
+        cache: &<VecMutator<T, M> as Mutator<Vec<T>>>::Cache,
+        _random_step: &Self::RandomStep,
+        max_cplx: f64,
+    ) -> Self::Concrete<'a> {
+        let nothing = ConcreteInsertComparedBytes {
+            els: vec![],
+            added_cplx: 0.,
+            idx: 0,
+        };
+        let (operand, size) = if let Some(operand) = compared_operand(&mutator.rng) {
+            operand
+        } else {
+            return nothing;
+        };
+        if value.len() + size > *mutator.len_range.end() {
+            return nothing;
+        }
+        let bytes = if mutator.rng.bool() {
+            operand.to_le_bytes()[..size].to_vec()
+        } else {
+            operand.to_be_bytes()[8 - size..].to_vec()
+        };
+        // T is u8, this was checked by `default_random_step`
+        let els = *(Box::new(bytes) as Box<dyn Any>).downcast::<Vec<T>>().unwrap();
+        let mut added_cplx = 0.;
+        for el in els.iter() {
+            if let Some(el_cache) = mutator.m.validate_value(el) {
+                added_cplx += mutator.m.complexity(el, &el_cache);
+            } else {
+                return nothing;
+            }
+        }
+        if mutator.complexity_from_inner(cache.sum_cplx + added_cplx, value.len() + els.len()) > max_cplx {
+            return nothing;
+        }
+        ConcreteInsertComparedBytes {
+            els,
+            added_cplx,
+            idx: mutator.rng.usize(..=value.len()),
+        }
+    }
+
+    #[no_coverage]
+    fn default_step(
+        &self,
+        mutator: &VecMutator<T, M>,
+        value: &Vec<T>,
+        _cache: &<VecMutator<T, M> as Mutator<Vec<T>>>::Cache,
+    ) -> Option<Self::Step> {
+        self.default_random_step(mutator, value)
+    }
+
+    #[no_coverage]
+    fn from_step<'a>(
+        mutator: &VecMutator<T, M>,
+        value: &Vec<T>,
+        cache: &<VecMutator<T, M> as Mutator<Vec<T>>>::Cache,
+        step: &'a mut Self::Step,
+        max_cplx: f64,
+    ) -> Option<Self::Concrete<'a>> {
+        let concrete = Self::random(mutator, value, cache, step, max_cplx);
+        if concrete.els.is_empty() {
+            None
+        } else {
+            Some(concrete)
+        }
+    }
+
+    #[no_coverage]
+    fn apply<'a>(
+        mutation: Self::Concrete<'a>,
+        mutator: &VecMutator<T, M>,
+        value: &mut Vec<T>,
+        cache: &mut <VecMutator<T, M> as Mutator<Vec<T>>>::Cache,
+        _max_cplx: f64,
+    ) -> (Self::Revert, f64) {
+        let idcs = mutation.idx..mutation.idx + mutation.els.len();
+        insert_many(value, mutation.idx, mutation.els.into_iter());
+        let cplx = mutator.complexity_from_inner(cache.sum_cplx + mutation.added_cplx, value.len());
+        (RevertInsertComparedBytes { idcs }, cplx)
+    }
+}
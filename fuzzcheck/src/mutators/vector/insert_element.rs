@@ -34,6 +34,11 @@ where
     ) {
         let _ = value.remove(self.idx);
     }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, _value: &Vec<T>) -> String {
+        format!("insert an element at index {}", self.idx)
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for InsertElement
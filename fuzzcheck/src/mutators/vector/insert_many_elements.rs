@@ -23,7 +23,7 @@ pub struct ConcreteInsertManyElements<T> {
     idx: usize,
 }
 pub struct RevertInsertManyElements {
-    idcs: Range<usize>,
+    pub idcs: Range<usize>,
 }
 
 impl<T, M> RevertMutation<Vec<T>, VecMutator<T, M>> for RevertInsertManyElements
@@ -40,6 +40,11 @@ where
     ) {
         let _ = value.drain(self.idcs);
     }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, _value: &Vec<T>) -> String {
+        format!("insert {} elements at index {}", self.idcs.len(), self.idcs.start)
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for InsertManyElements
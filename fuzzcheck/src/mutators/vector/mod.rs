@@ -4,9 +4,12 @@ use std::cmp;
 use std::marker::PhantomData;
 use std::ops::RangeInclusive;
 
+use self::insert_many_elements::{insert_many, RevertInsertManyElements};
+use self::mutate_element::RevertMutateElement;
 use self::vec_mutation::{RevertVectorMutation, VectorMutation, VectorMutationRandomStep, VectorMutationStep};
 
 pub mod arbitrary;
+pub mod insert_compared_bytes;
 pub mod insert_element;
 pub mod insert_many_elements;
 pub mod mutate_element;
@@ -321,6 +324,105 @@ where
             result
         }
     }
+
+    /// First remove chunks of elements, starting with the largest ones, then simplify each element
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &Vec<T>, cache: &Self::Cache, candidate: &mut dyn FnMut(Vec<T>) -> bool) -> bool {
+        let mut chunk_len = value.len().saturating_sub(*self.len_range.start());
+        while chunk_len > 0 {
+            let mut start = 0;
+            while start + chunk_len <= value.len() {
+                let mut simpler = Vec::with_capacity(value.len() - chunk_len);
+                simpler.extend_from_slice(&value[..start]);
+                simpler.extend_from_slice(&value[start + chunk_len..]);
+                if candidate(simpler) {
+                    return true;
+                }
+                start += chunk_len;
+            }
+            chunk_len /= 2;
+        }
+        for (i, (el, el_cache)) in value.iter().zip(cache.inner.iter()).enumerate() {
+            let accepted = self.m.ordered_simplify(
+                el,
+                el_cache,
+                &mut #[no_coverage]
+                |simpler_el| {
+                    let mut simpler = value.clone();
+                    simpler[i] = simpler_el;
+                    candidate(simpler)
+                },
+            );
+            if accepted {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Either mutate one element by crossing it over with an element of `other`,
+    /// or splice a slice of `other` into `value`
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut Vec<T>,
+        cache: &mut Self::Cache,
+        other: &Vec<T>,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        if other.is_empty() {
+            return None;
+        }
+        let value_cplx = self.complexity(value, cache);
+        let max_added_len = self.len_range.end().saturating_sub(value.len());
+        if !value.is_empty() && (max_added_len == 0 || self.rng.bool()) {
+            let idx = self.rng.usize(..value.len());
+            let other_idx = self.rng.usize(..other.len());
+            let el_cplx = self.m.complexity(&value[idx], &cache.inner[idx]);
+            let max_el_cplx = max_cplx - value_cplx + el_cplx;
+            let (t, new_el_cplx) = self.m.crossover_mutate(
+                &mut value[idx],
+                &mut cache.inner[idx],
+                &other[other_idx],
+                &other_cache.inner[other_idx],
+                max_el_cplx,
+            )?;
+            let cplx = self.complexity_from_inner(cache.sum_cplx - el_cplx + new_el_cplx, value.len());
+            let revert = RevertMutateElement {
+                idx,
+                unmutate_token: Some(t),
+            };
+            return Some((RevertVectorMutation::MutateElement(revert), cplx));
+        }
+        if max_added_len == 0 {
+            return None;
+        }
+        let len = self.rng.usize(1..=cmp::min(other.len(), max_added_len));
+        let start = self.rng.usize(..=other.len() - len);
+        let added_cplx = (start..start + len)
+            .map(
+                #[no_coverage]
+                |i| self.m.complexity(&other[i], &other_cache.inner[i]),
+            )
+            .sum::<f64>();
+        let cplx = self.complexity_from_inner(cache.sum_cplx + added_cplx, value.len() + len);
+        if cplx > max_cplx {
+            return None;
+        }
+        let idx = self.rng.usize(..=value.len());
+        insert_many(value, idx, other[start..start + len].iter().cloned());
+        let revert = RevertInsertManyElements { idcs: idx..idx + len };
+        Some((RevertVectorMutation::InsertManyElements(revert), cplx))
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &Vec<T>, token: &Self::UnmutateToken) -> String {
+        token.describe(self, value)
+    }
 }
 
 impl<T, M> VecMutator<T, M>
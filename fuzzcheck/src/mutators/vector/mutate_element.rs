@@ -46,6 +46,19 @@ where
                 .unmutate(&mut value[idx], &mut cache.inner[self.idx], unmutate_token)
         }
     }
+
+    #[no_coverage]
+    fn describe(&self, mutator: &VecMutator<T, M>, value: &Vec<T>) -> String {
+        if let Some(unmutate_token) = &self.unmutate_token {
+            format!(
+                "element {}: {}",
+                self.idx,
+                mutator.m.describe_mutation(&value[self.idx], unmutate_token)
+            )
+        } else {
+            format!("element {}: unchanged", self.idx)
+        }
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for MutateElement
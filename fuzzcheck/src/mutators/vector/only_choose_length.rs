@@ -56,6 +56,11 @@ where
     ) {
         let _ = std::mem::swap(value, &mut self.replace_by);
     }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, value: &Vec<T>) -> String {
+        format!("replace with a vector of {} identical elements", value.len())
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for OnlyChooseLength
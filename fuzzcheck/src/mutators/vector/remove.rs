@@ -31,6 +31,11 @@ where
     ) {
         value.insert(self.idx, self.element);
     }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, _value: &Vec<T>) -> String {
+        format!("remove the element at index {}", self.idx)
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for Remove
@@ -34,6 +34,14 @@ where
         let _ = value.remove(self.remove_at_idx);
         value.insert(self.insert_at_idx, self.insert_el);
     }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, _value: &Vec<T>) -> String {
+        format!(
+            "remove the element at index {} and insert an element at index {}",
+            self.insert_at_idx, self.remove_at_idx
+        )
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for RemoveAndInsertElement
@@ -34,6 +34,11 @@ where
     ) {
         value.swap(self.idx_1, self.idx_2);
     }
+
+    #[no_coverage]
+    fn describe(&self, _mutator: &VecMutator<T, M>, _value: &Vec<T>) -> String {
+        format!("swap elements {} and {}", self.idx_1, self.idx_2)
+    }
 }
 
 impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for SwapElements
@@ -1,4 +1,5 @@
 use super::arbitrary;
+use super::insert_compared_bytes;
 use super::insert_element;
 use super::insert_many_elements;
 use super::mutate_element;
@@ -148,6 +149,14 @@ macro_rules! impl_vec_mutation {
                     ),*
                 }
             }
+            #[no_coverage]
+            fn describe(&self, mutator: &VecMutator<T, M>, value: &Vec<T>) -> String {
+                match self {
+                    $(
+                        Self::$i(r) => r.describe(mutator, value)
+                    ),*
+                }
+            }
         }
 
         impl<T, M> Mutation<Vec<T>, VecMutator<T, M>> for VectorMutation
@@ -313,7 +322,8 @@ impl_vec_mutation! {
     (InsertManyElements, insert_many_elements::InsertManyElements),
     (RemoveAndInsertElement, remove_and_insert_element::RemoveAndInsertElement),
     (OnlyChooseLength, only_choose_length::OnlyChooseLength),
-    (Arbitrary, arbitrary::Arbitrary)
+    (Arbitrary, arbitrary::Arbitrary),
+    (InsertComparedBytes, insert_compared_bytes::InsertComparedBytes)
 }
 
 // ====== Default Vector Mutations =====
@@ -409,6 +419,11 @@ impl Default for VectorMutation {
                     random_weight: 8.,
                     ordered_weight: 4.,
                 },
+                WeightedMutation {
+                    mutation: InnerVectorMutation::InsertComparedBytes(insert_compared_bytes::InsertComparedBytes),
+                    random_weight: 20.,
+                    ordered_weight: 20.,
+                },
                 // WeightedMutation {
                 //     mutation: InnerVectorMutation::InsertManyElements(insert_many_elements::InsertManyElements {
                 //         nbr_added_elements: 4,
@@ -0,0 +1,403 @@
+//! Types implementing the [Reporter] trait.
+//!
+//! There are currently three implementations:
+//!
+//! * [TerminalReporter] prints the events and messages of the fuzzer to stdout,
+//! with colours. It is the default reporter.
+//!
+//! * [QuietReporter] ignores them.
+//!
+//! * JsonReporter writes each of them as a JSON object on its own line. It
+//! requires the `serde_json_serializer` feature.
+
+use std::time::Duration;
+
+use fuzzcheck_common::{FuzzerEvent, FuzzerStats};
+use nu_ansi_term::Color;
+
+use crate::fuzzer::{ReplayOutcome, ReplayReport};
+use crate::traits::{Reporter, Stats};
+
+/// A reporter that prints the events and messages of the fuzzer to stdout.
+#[derive(Default)]
+pub struct TerminalReporter;
+
+impl Reporter for TerminalReporter {
+    #[no_coverage]
+    fn report_event(
+        &mut self,
+        event: FuzzerEvent,
+        time_since_start: Duration,
+        stats: Option<(&FuzzerStats, &dyn Stats)>,
+    ) {
+        // println uses a lock, which may mess up the signal handling
+        let time_since_start_display = {
+            let time_since_start_millis = time_since_start.as_millis();
+            if time_since_start_millis > 10_000 {
+                let time_since_start_seconds = time_since_start.as_secs();
+                format!("{}s ", time_since_start_seconds)
+            } else {
+                format!("{}ms ", time_since_start_millis)
+            }
+        };
+        print!("{} ", time_since_start_display);
+        match event {
+            FuzzerEvent::Start => {
+                println!("{}", Color::Yellow.paint("START"));
+                return;
+            }
+            FuzzerEvent::Pulse => {
+                print!("{} ", Color::Yellow.paint("PULSE"));
+            }
+            FuzzerEvent::Stop => {
+                println!("\n======================== STOPPED ========================");
+                println!(r#"The fuzzer was stopped."#);
+                return;
+            }
+            FuzzerEvent::End => {
+                println!("\n======================== END ========================");
+                println!(
+                    r#"Fuzzcheck cannot generate more arbitrary values of the input type. This may be
+because all possible values under the chosen maximum complexity were tested, or
+because the mutator does not know how to generate more values."#
+                );
+                return;
+            }
+            FuzzerEvent::CrashNoInput => {
+                println!("\n=================== CRASH DETECTED ===================");
+                println!(
+                    r#"A crash was detected, but the fuzzer cannot recover the crashing input.
+This should never happen, and is probably a bug in fuzzcheck. Sorry :("#
+                );
+                return;
+            }
+            FuzzerEvent::Done => {
+                println!("{}", Color::Yellow.paint("DONE"));
+                return;
+            }
+            FuzzerEvent::DidReadCorpus => {
+                println!("{}", Color::Yellow.paint("FINISHED READING CORPUS"));
+                return;
+            }
+            FuzzerEvent::CaughtSignal(signal) => println!("\n================ SIGNAL {} ================", signal),
+
+            FuzzerEvent::TestFailure => {
+                println!("\n================ TEST FAILED ================");
+            }
+            FuzzerEvent::Timeout => {
+                println!("\n================ TIMEOUT ================");
+            }
+            FuzzerEvent::Replace(_, _) => {}
+            FuzzerEvent::None => return,
+        };
+        if let Some((fuzzer_stats, pool_stats)) = stats {
+            print!(
+                "{} ",
+                Color::Yellow.paint(format!("{}", fuzzer_stats.total_number_of_runs))
+            );
+            print!("{} ", Color::Yellow.paint(format!("{}", pool_stats)));
+            print!(
+                "{} ",
+                Color::Yellow.paint(format!("iter/s {}", fuzzer_stats.exec_per_s))
+            );
+
+            println!();
+        }
+    }
+
+    #[no_coverage]
+    fn report_message(&mut self, message: &str) {
+        println!("{}", message);
+    }
+
+    #[no_coverage]
+    fn report_replay(&mut self, report: &ReplayReport) {
+        for (path, outcome) in &report.results {
+            match outcome {
+                ReplayOutcome::Failed(failure) => {
+                    println!("{} {}", Color::Red.paint("FAILED"), path.display());
+                    for line in failure.lines() {
+                        println!("    {}", line);
+                    }
+                }
+                ReplayOutcome::TimedOut => println!("{} {}", Color::Red.paint("TIMED OUT"), path.display()),
+                ReplayOutcome::Passed | ReplayOutcome::Invalid => {}
+            }
+        }
+        if report.found_test_failure() {
+            println!("{}", Color::Red.paint(report.summary()));
+        } else {
+            println!("{}", Color::Green.paint(report.summary()));
+        }
+    }
+}
+
+/// A reporter that ignores the events and messages of the fuzzer.
+#[derive(Default)]
+pub struct QuietReporter;
+
+impl Reporter for QuietReporter {
+    #[no_coverage]
+    fn report_event(
+        &mut self,
+        _event: FuzzerEvent,
+        _time_since_start: Duration,
+        _stats: Option<(&FuzzerStats, &dyn Stats)>,
+    ) {
+    }
+
+    #[no_coverage]
+    fn report_message(&mut self, _message: &str) {}
+}
+
+#[cfg(feature = "serde_json_serializer")]
+pub use json_reporter::JsonReporter;
+
+#[cfg(feature = "serde_json_serializer")]
+mod json_reporter {
+    use std::io::Write;
+    use std::time::Duration;
+
+    use fuzzcheck_common::{FuzzerEvent, FuzzerStats};
+
+    use crate::traits::{Reporter, Stats};
+    use crate::CSVField;
+
+    /// A reporter that writes each event and message of the fuzzer as a JSON object on its own line.
+    ///
+    /// An event is written as `{"event": <name>, "time_ms": <time since start>, ..}`, with the fields
+    /// `total_runs`, `exec_per_s`, and `pool` (an object holding the statistics of the pool) if
+    /// the statistics are available. A message is written as `{"message": <message>}`.
+    ///
+    /// If the writer fails, for example because the process reading the JSON lines has exited,
+    /// the error is ignored and nothing else is written.
+    #[doc(cfg(feature = "serde_json_serializer"))]
+    pub struct JsonReporter<W: Write> {
+        writer: W,
+        failed: bool,
+    }
+
+    impl<W: Write> JsonReporter<W> {
+        #[no_coverage]
+        pub fn new(writer: W) -> Self {
+            Self { writer, failed: false }
+        }
+
+        #[no_coverage]
+        fn write_line(&mut self, object: serde_json::Map<String, serde_json::Value>) {
+            if self.failed {
+                return;
+            }
+            let mut line = serde_json::to_vec(&object).unwrap();
+            line.push(b'\n');
+            if self.writer.write_all(&line).is_err() {
+                self.failed = true;
+            }
+        }
+    }
+
+    impl<W: Write + 'static> Reporter for JsonReporter<W> {
+        #[no_coverage]
+        fn report_event(
+            &mut self,
+            event: FuzzerEvent,
+            time_since_start: Duration,
+            stats: Option<(&FuzzerStats, &dyn Stats)>,
+        ) {
+            let mut object = serde_json::Map::new();
+            let name = match event {
+                FuzzerEvent::Start => "start",
+                FuzzerEvent::Stop => "stop",
+                FuzzerEvent::End => "end",
+                FuzzerEvent::CrashNoInput => "crash_no_input",
+                FuzzerEvent::Pulse => "pulse",
+                FuzzerEvent::Done => "done",
+                FuzzerEvent::Replace(added, removed) => {
+                    object.insert("added".to_string(), added.into());
+                    object.insert("removed".to_string(), removed.into());
+                    "replace"
+                }
+                FuzzerEvent::DidReadCorpus => "did_read_corpus",
+                FuzzerEvent::CaughtSignal(signal) => {
+                    object.insert("signal".to_string(), signal.into());
+                    "caught_signal"
+                }
+                FuzzerEvent::TestFailure => "test_failure",
+                FuzzerEvent::Timeout => "timeout",
+                FuzzerEvent::None => return,
+            };
+            object.insert("event".to_string(), name.into());
+            object.insert("time_ms".to_string(), (time_since_start.as_millis() as u64).into());
+            if let Some((fuzzer_stats, pool_stats)) = stats {
+                object.insert("total_runs".to_string(), fuzzer_stats.total_number_of_runs.into());
+                object.insert("exec_per_s".to_string(), fuzzer_stats.exec_per_s.into());
+                let mut pool = serde_json::Map::new();
+                for (header, field) in pool_stats.csv_headers().into_iter().zip(pool_stats.to_csv_record()) {
+                    let header = match header {
+                        CSVField::String(header) => header,
+                        CSVField::Integer(header) => header.to_string(),
+                        CSVField::Float(header) => header.to_string(),
+                    };
+                    let value = match field {
+                        CSVField::Integer(n) => n.into(),
+                        CSVField::Float(n) => n.into(),
+                        CSVField::String(s) => s.into(),
+                    };
+                    pool.insert(header, value);
+                }
+                object.insert("pool".to_string(), pool.into());
+            }
+            self.write_line(object);
+        }
+
+        #[no_coverage]
+        fn report_message(&mut self, message: &str) {
+            let mut object = serde_json::Map::new();
+            object.insert("message".to_string(), message.into());
+            self.write_line(object);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde_json_serializer"))]
+mod tests {
+    use std::cell::RefCell;
+    use std::fmt::Display;
+    use std::io::{self, Write};
+    use std::path::PathBuf;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use fuzzcheck_common::{FuzzerEvent, FuzzerStats};
+
+    use super::JsonReporter;
+    use crate::fuzzer::{ReplayOutcome, ReplayReport};
+    use crate::traits::{Reporter, Stats};
+    use crate::{CSVField, ToCSV};
+
+    /// A writer whose content can be read while the reporter owns it
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        #[no_coverage]
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+        #[no_coverage]
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// A writer that fails like a pipe whose reader has exited, and counts the attempts to write to it
+    #[derive(Clone, Default)]
+    struct BrokenPipe(Rc<RefCell<usize>>);
+
+    impl Write for BrokenPipe {
+        #[no_coverage]
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            *self.0.borrow_mut() += 1;
+            Err(io::Error::from(io::ErrorKind::BrokenPipe))
+        }
+        #[no_coverage]
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct PoolStats;
+
+    impl Display for PoolStats {
+        #[no_coverage]
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "cov: 12")
+        }
+    }
+    impl ToCSV for PoolStats {
+        #[no_coverage]
+        fn csv_headers(&self) -> Vec<CSVField> {
+            vec![CSVField::String("cov".to_owned()), CSVField::String("name".to_owned())]
+        }
+        #[no_coverage]
+        fn to_csv_record(&self) -> Vec<CSVField> {
+            vec![CSVField::Integer(12), CSVField::String("pool".to_owned())]
+        }
+    }
+    impl Stats for PoolStats {}
+
+    #[no_coverage]
+    fn lines(buffer: &SharedBuffer) -> Vec<String> {
+        String::from_utf8(buffer.0.borrow().clone())
+            .unwrap()
+            .lines()
+            .map(str::to_owned)
+            .collect()
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_json_reporter_output() {
+        let buffer = SharedBuffer::default();
+        let mut reporter = JsonReporter::new(buffer.clone());
+        let fuzzer_stats = FuzzerStats {
+            total_number_of_runs: 100,
+            number_of_runs_since_last_reset_time: 10,
+            exec_per_s: 50,
+        };
+        reporter.report_event(FuzzerEvent::Start, Duration::from_millis(3), None);
+        reporter.report_event(
+            FuzzerEvent::Replace(2, 1),
+            Duration::from_millis(1500),
+            Some((&fuzzer_stats, &PoolStats)),
+        );
+        reporter.report_event(FuzzerEvent::CaughtSignal(11), Duration::from_millis(2000), None);
+        reporter.report_event(FuzzerEvent::None, Duration::from_millis(2000), None);
+        reporter.report_message("line 1\nline \"2\"");
+        assert_eq!(
+            lines(&buffer),
+            [
+                r#"{"event":"start","time_ms":3}"#,
+                concat!(
+                    r#"{"added":2,"event":"replace","exec_per_s":50,"pool":{"cov":12,"name":"pool"},"#,
+                    r#""removed":1,"time_ms":1500,"total_runs":100}"#
+                ),
+                r#"{"event":"caught_signal","signal":11,"time_ms":2000}"#,
+                r#"{"message":"line 1\nline \"2\""}"#,
+            ]
+        );
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_json_reporter_replay() {
+        let buffer = SharedBuffer::default();
+        let mut reporter = JsonReporter::new(buffer.clone());
+        let report = ReplayReport {
+            results: vec![
+                (PathBuf::from("a.json"), ReplayOutcome::Passed),
+                (PathBuf::from("b.json"), ReplayOutcome::Failed("oops".to_owned())),
+                (PathBuf::from("c.json"), ReplayOutcome::TimedOut),
+                (PathBuf::from("d.json"), ReplayOutcome::Invalid),
+            ],
+        };
+        reporter.report_replay(&report);
+        let message = concat!(
+            "FAILED b.json\n    oops\nTIMED OUT c.json\n",
+            "replayed 4 test cases: 1 passed, 1 failed, 1 timed out, 1 invalid"
+        );
+        let expected = serde_json::json!({ "message": message }).to_string();
+        assert_eq!(lines(&buffer), [expected]);
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_json_reporter_stops_writing_after_an_error() {
+        let writer = BrokenPipe::default();
+        let mut reporter = JsonReporter::new(writer.clone());
+        reporter.report_event(FuzzerEvent::Start, Duration::from_millis(0), None);
+        reporter.report_message("ignored");
+        reporter.report_event(FuzzerEvent::Stop, Duration::from_millis(1), None);
+        assert_eq!(*writer.0.borrow(), 1);
+    }
+}
@@ -110,6 +110,40 @@ where
     fn weight(&self) -> f64 {
         self.p1_weight() + self.p2_weight()
     }
+
+    #[no_coverage]
+    fn checkpoint(&self) -> Vec<u8> {
+        encode_checkpoint(
+            self.p1_number_times_chosen_since_last_progress,
+            self.p2_number_times_chosen_since_last_progress,
+            self.p1.checkpoint(),
+            self.p2.checkpoint(),
+        )
+    }
+
+    #[no_coverage]
+    fn restore_checkpoint(&mut self, checkpoint: &[u8]) {
+        if let Some((n1, n2, c1, c2)) = decode_checkpoint(checkpoint) {
+            self.p1_number_times_chosen_since_last_progress = n1;
+            self.p2_number_times_chosen_since_last_progress = n2;
+            self.p1.restore_checkpoint(&c1);
+            self.p2.restore_checkpoint(&c2);
+        }
+    }
+}
+
+/// Serialize the number of times each of the two pools was chosen since it last made progress,
+/// which determines their relative weights, along with the checkpoints of the pools themselves
+#[no_coverage]
+fn encode_checkpoint(n1: usize, n2: usize, c1: Vec<u8>, c2: Vec<u8>) -> Vec<u8> {
+    bincode::serialize(&(n1 as u64, n2 as u64, c1, c2)).unwrap()
+}
+
+#[no_coverage]
+fn decode_checkpoint(checkpoint: &[u8]) -> Option<(usize, usize, Vec<u8>, Vec<u8>)> {
+    let (n1, n2, c1, c2) = bincode::deserialize::<(u64, u64, Vec<u8>, Vec<u8>)>(checkpoint).ok()?;
+    // the weight of each pool is divided by these numbers, so they must not be 0
+    Some((std::cmp::max(n1, 1) as usize, std::cmp::max(n2, 1) as usize, c1, c2))
 }
 
 impl<P1, P2, SensorMarker> SaveToStatsFolder for AndPool<P1, P2, SensorMarker>
@@ -359,4 +393,76 @@ impl SensorAndPool for AndSensorAndPool {
             self.sap1.get_random_index()
         }
     }
+
+    #[no_coverage]
+    fn checkpoint(&self) -> Vec<u8> {
+        encode_checkpoint(
+            self.sap1_number_times_chosen_since_last_progress,
+            self.sap2_number_times_chosen_since_last_progress,
+            self.sap1.checkpoint(),
+            self.sap2.checkpoint(),
+        )
+    }
+
+    #[no_coverage]
+    fn restore_checkpoint(&mut self, checkpoint: &[u8]) {
+        if let Some((n1, n2, c1, c2)) = decode_checkpoint(checkpoint) {
+            self.sap1_number_times_chosen_since_last_progress = n1;
+            self.sap2_number_times_chosen_since_last_progress = n2;
+            self.sap1.restore_checkpoint(&c1);
+            self.sap2.restore_checkpoint(&c2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sensors_and_pools::UnitPool;
+
+    #[no_coverage]
+    fn new_pool() -> AndPool<AndPool<UnitPool, UnitPool, SameObservations>, UnitPool, SameObservations> {
+        let p1 = AndPool::new(
+            UnitPool::new(PoolStorageIndex::mock(0)),
+            UnitPool::new(PoolStorageIndex::mock(1)),
+            1.0,
+            2.0,
+        );
+        AndPool::new(p1, UnitPool::new(PoolStorageIndex::mock(2)), 1.0, 1.0)
+    }
+
+    #[test]
+    #[no_coverage]
+    fn test_restore_checkpoint() {
+        let mut pool = new_pool();
+        for _ in 0..100 {
+            pool.get_random_index();
+        }
+        let checkpoint = pool.checkpoint();
+
+        let mut restored = new_pool();
+        restored.restore_checkpoint(&checkpoint);
+        assert_eq!(
+            restored.p1_number_times_chosen_since_last_progress,
+            pool.p1_number_times_chosen_since_last_progress
+        );
+        assert_eq!(
+            restored.p2_number_times_chosen_since_last_progress,
+            pool.p2_number_times_chosen_since_last_progress
+        );
+        assert_eq!(
+            restored.p1.p1_number_times_chosen_since_last_progress,
+            pool.p1.p1_number_times_chosen_since_last_progress
+        );
+        assert_eq!(
+            restored.p1.p2_number_times_chosen_since_last_progress,
+            pool.p1.p2_number_times_chosen_since_last_progress
+        );
+        assert_eq!(restored.weight(), pool.weight());
+
+        // an invalid checkpoint leaves the pool unchanged
+        let mut pool = new_pool();
+        pool.restore_checkpoint(&[1, 2, 3]);
+        assert_eq!(pool.p1_number_times_chosen_since_last_progress, 1);
+    }
 }
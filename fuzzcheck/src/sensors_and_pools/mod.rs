@@ -17,6 +17,8 @@ mod unit_pool;
 #[doc(inline)]
 pub use crate::code_coverage_sensor::CodeCoverageSensor;
 #[doc(inline)]
+pub use crate::comparisons_sensor::ComparisonsSensor;
+#[doc(inline)]
 pub use and_sensor_and_pool::{AndPool, AndSensor, AndSensorAndPool, DifferentObservations, SameObservations};
 #[doc(inline)]
 pub use array_of_counters::ArrayOfCounters;
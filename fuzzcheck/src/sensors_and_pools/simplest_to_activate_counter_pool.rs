@@ -252,7 +252,9 @@ impl SimplestToActivateCounterPool {
             element.least_complex_for_counters.insert(f);
         }
 
-        let mut affected_counters = AHashSet::<CounterIdx>::new();
+        // the scores of the affected counters are updated in the iteration order of the set, which must not depend
+        // on a random hasher for the fuzzer to be reproducible
+        let mut affected_counters = AHashSet::<CounterIdx>::with_hasher(ahash::RandomState::with_seeds(0, 0, 0, 0));
 
         let deleted_values: Vec<_> = to_delete.iter().copied().collect();
         let deleted_pool_storage_indices = deleted_values
@@ -2,7 +2,9 @@ use crate::traits::{CompatibleWithObservations, CorpusDelta, Pool, SaveToStatsFo
 use crate::PoolStorageIndex;
 use crate::{CSVField, ToCSV};
 use nu_ansi_term::Color;
-use std::fmt::Display;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 
 const NBR_ARTIFACTS_PER_ERROR_AND_CPLX: usize = 8;
@@ -12,10 +14,51 @@ pub(crate) static mut TEST_FAILURE: Option<TestFailure> = None;
 /// A type describing a test failure.
 ///
 /// It is uniquely identifiable through `self.id` and displayable through `self.display`.
+///
+/// By default, the id of a panic is derived from its location. If the fuzzer was asked to identify
+/// test failures by their backtrace, it is instead derived from the innermost frames of the backtrace
+/// that belong to the tested crate, which are then available through [`self.frames()`](TestFailure::frames).
+/// Use [`TestFailure::new`] to create a test failure without a backtrace.
 #[derive(Debug, Clone)]
 pub struct TestFailure {
     pub display: String,
     pub id: u64,
+    pub(crate) frames: Vec<String>,
+}
+
+impl TestFailure {
+    /// A test failure displayed as `display` and identified by `id`, without a backtrace
+    #[no_coverage]
+    pub fn new(display: String, id: u64) -> Self {
+        Self {
+            display,
+            id,
+            frames: vec![],
+        }
+    }
+    /// The innermost frames of the backtrace of the test failure that belong to the tested crate,
+    /// formatted as `function (file:line)`.
+    ///
+    /// It is empty unless the fuzzer was asked to identify test failures by their backtrace.
+    #[no_coverage]
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+    /// The test failure caused by a test function returning `Err(error)`.
+    ///
+    /// It is displayed as the debug description of the error and its id is derived from the type of
+    /// the error and, if it is an enum, from its variant.
+    #[no_coverage]
+    pub(crate) fn from_error<E: Debug>(error: &E) -> Self {
+        let mut hasher = DefaultHasher::new();
+        std::any::type_name::<E>().hash(&mut hasher);
+        std::mem::discriminant(error).hash(&mut hasher);
+        Self {
+            display: format!("{:?}", error),
+            id: hasher.finish(),
+            frames: vec![],
+        }
+    }
 }
 
 /// A sensor that records test failures.
@@ -227,3 +270,30 @@ impl CompatibleWithObservations<Option<TestFailure>> for TestFailurePool {
         vec![]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TestFailure;
+
+    #[derive(Debug)]
+    enum Error {
+        NotFound(u8),
+        Invalid,
+    }
+
+    #[test]
+    fn test_from_error() {
+        let failure = TestFailure::from_error(&Error::NotFound(1));
+        assert_eq!(failure.display, "NotFound(1)");
+        assert!(failure.frames().is_empty());
+        // the errors of the same variant are the same kind of test failure, whatever their content
+        assert_eq!(failure.id, TestFailure::from_error(&Error::NotFound(2)).id);
+        assert_ne!(failure.id, TestFailure::from_error(&Error::Invalid).id);
+        // and so are the errors of the same type
+        assert_eq!(TestFailure::from_error(&"a").id, TestFailure::from_error(&"b").id);
+        assert_ne!(
+            TestFailure::from_error(&"a").id,
+            TestFailure::from_error(&"a".to_owned()).id
+        );
+    }
+}
@@ -85,9 +85,9 @@ where
     pub fn new(name: &str, size: usize) -> Self {
         Self {
             name: name.to_string(),
-            complexities: vec![AHashMap::new(); size],
+            complexities: vec![AHashMap::with_hasher(ahash::RandomState::with_seeds(0, 0, 0, 0)); size],
             inputs: Slab::new(),
-            best_input_for_value: vec![AHashMap::new(); size],
+            best_input_for_value: vec![AHashMap::with_hasher(ahash::RandomState::with_seeds(0, 0, 0, 0)); size],
             ranked_inputs: FenwickTree::new(vec![]),
             stats: UniqueValuesPoolStats {
                 name: name.to_string(),
@@ -187,7 +187,7 @@ where
         let cplx = complexity;
         let input = input_id;
         let input = Input {
-            best_for_values: AHashSet::new(), // fill in later! with new_observations.into_iter().collect(),
+            best_for_values: AHashSet::with_hasher(ahash::RandomState::with_seeds(0, 0, 0, 0)), // fill in later! with new_observations.into_iter().collect(),
             data: input,
             score,
             number_times_chosen: 1,
@@ -2,10 +2,11 @@
 // ! recover from crashes.
 
 use std::ptr;
+use std::time::Duration;
 
 use libc::{
-    sigaction, sigemptyset, SA_NODEFER, SA_ONSTACK, SA_SIGINFO, SIGABRT, SIGBUS, SIGFPE, SIGINT, SIGSEGV, SIGTERM,
-    SIGTRAP, SIG_DFL,
+    sigaction, sigemptyset, SA_NODEFER, SA_ONSTACK, SA_SIGINFO, SIGABRT, SIGALRM, SIGBUS, SIGFPE, SIGINT, SIGSEGV,
+    SIGTERM, SIGTRAP, SIG_DFL,
 };
 
 static mut SIGNAL_HANDLER: Option<Box<dyn Fn(libc::c_int) -> !>> = None;
@@ -30,7 +31,9 @@ where
 {
     SIGNAL_HANDLER = Some(Box::new(f));
 
-    let stack_size = libc::SIGSTKSZ;
+    // the handler serializes the current test case and writes it to the file system,
+    // which needs a lot more stack space than SIGSTKSZ
+    let stack_size = std::cmp::max(libc::SIGSTKSZ, 1 << 20);
 
     let stack_pointer = std::alloc::alloc_zeroed(std::alloc::Layout::array::<std::ffi::c_void>(stack_size).unwrap())
         as *mut std::ffi::c_void;
@@ -52,7 +55,9 @@ where
     sa.sa_flags = SA_NODEFER | SA_SIGINFO | SA_ONSTACK;
     sa.sa_sigaction = os_handler as usize;
 
-    let signals = [SIGINT, SIGTERM, SIGSEGV, SIGBUS, SIGABRT, SIGFPE, SIGABRT, SIGTRAP];
+    let signals = [
+        SIGINT, SIGTERM, SIGSEGV, SIGBUS, SIGABRT, SIGFPE, SIGABRT, SIGTRAP, SIGALRM,
+    ];
     for sig in signals {
         if sigaction(sig as i32, &mut sa as *mut sigaction, ptr::null_mut()) < 0 {
             panic!("Could not set up signal handler");
@@ -66,9 +71,42 @@ pub(crate) unsafe fn reset_signal_handlers() {
     sigemptyset(&mut sa.sa_mask as *mut libc::sigset_t);
     sa.sa_sigaction = SIG_DFL;
 
-    for &signal in &[SIGINT, SIGTERM, SIGSEGV, SIGBUS, SIGABRT, SIGFPE, SIGABRT, SIGTRAP] {
+    for &signal in &[
+        SIGINT, SIGTERM, SIGSEGV, SIGBUS, SIGABRT, SIGFPE, SIGABRT, SIGTRAP, SIGALRM,
+    ] {
         if sigaction(signal, &mut sa as *mut sigaction, ptr::null_mut()) < 0 {
             panic!("Could not set up signal handler");
         }
     }
 }
+
+/// Arm a timer that will raise `SIGALRM` once the given duration has elapsed.
+///
+/// Only one timer exists at a time: calling this function again replaces the previous timer.
+#[no_coverage]
+pub(crate) fn start_timer(duration: Duration) {
+    // a timer of zero microseconds would be disarmed instead
+    let duration = duration.max(Duration::from_micros(1));
+    let timer = libc::itimerval {
+        it_interval: libc::timeval { tv_sec: 0, tv_usec: 0 },
+        it_value: libc::timeval {
+            tv_sec: duration.as_secs() as libc::time_t,
+            tv_usec: duration.subsec_micros() as libc::suseconds_t,
+        },
+    };
+    unsafe {
+        libc::setitimer(libc::ITIMER_REAL, &timer, ptr::null_mut());
+    }
+}
+
+/// Disarm the timer previously armed by [`start_timer`]
+#[no_coverage]
+pub(crate) fn stop_timer() {
+    let timer = libc::itimerval {
+        it_interval: libc::timeval { tv_sec: 0, tv_usec: 0 },
+        it_value: libc::timeval { tv_sec: 0, tv_usec: 0 },
+    };
+    unsafe {
+        libc::setitimer(libc::ITIMER_REAL, &timer, ptr::null_mut());
+    }
+}
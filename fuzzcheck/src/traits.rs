@@ -3,14 +3,15 @@ The fuzzcheck_traits crate defines the `Mutator` and `Serializer` traits
 used by all fuzzcheck-related crates.
 */
 
-use crate::fuzzer::PoolStorageIndex;
+use crate::fuzzer::{PoolStorageIndex, ReplayOutcome, ReplayReport};
 use crate::sensors_and_pools::{AndPool, MapSensor};
-use fuzzcheck_common::FuzzerEvent;
+use fuzzcheck_common::{FuzzerEvent, FuzzerStats};
 use std::fmt::Display;
 use std::marker::PhantomData;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 
 /**
  A [`Mutator`] is an object capable of generating/mutating a value for the purpose of
@@ -147,6 +148,13 @@ will check whether the character is within a certain range.
 Note that in most cases, it is completely fine to never mutate a value’s cache,
 since it is recomputed by [`validate_value`](crate::Mutator::validate_value) when
 needed.
+
+A mutator that needs randomness should create its random number generator with
+`fastrand::Rng::new()` in its constructor, as the mutators of this crate do. The
+generator is then seeded from `fastrand`’s thread-local generator, which is what
+[`FuzzerBuilder1::seed`](crate::builder::FuzzerBuilder1::seed) and the `--seed` argument
+of `cargo fuzzcheck` seed. That only makes the fuzzer run reproducible if the mutator is
+constructed after the seed is set.
 **/
 pub trait Mutator<Value: Clone>: 'static {
     /// Accompanies each value to help compute its complexity and mutate it efficiently.
@@ -250,6 +258,105 @@ pub trait Mutator<Value: Clone>: 'static {
     where
         T: Clone + 'static,
         M: Mutator<T>;
+
+    /// Calls `candidate` on values that are strictly simpler than the given value, from the most to the
+    /// least aggressive simplification, until it returns `true`.
+    ///
+    /// A simpler value has a lower complexity or, for the same complexity, is closer to the simplest
+    /// value that the mutator can produce, such as `0` for an integer. The candidates must be valid
+    /// values of the mutator, but their complexity is not checked.
+    ///
+    /// It is used by the `minify` command to minify a failing test case by greedy delta-debugging:
+    /// the first candidate that still fails the test, with the same kind of test failure, replaces the
+    /// test case, which is then simplified again until none of its candidates fail in that way.
+    ///
+    /// Returns whether `candidate` returned `true`. By default, the value has no simpler candidates.
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &Value, cache: &Self::Cache, candidate: &mut dyn FnMut(Value) -> bool) -> bool {
+        let _ = (value, cache, candidate);
+        false
+    }
+
+    /// Mutates the value by combining it with another value, which is a different test case of the pool.
+    ///
+    /// For example, a part of the other vector can be spliced into the vector, or a field of the structure
+    /// can be replaced by the same field of the other structure. It is what allows the fuzzer to combine the
+    /// interesting parts of two test cases that were discovered independently.
+    ///
+    /// Like [`random_mutate`](Mutator::random_mutate), it returns a token that [`unmutate`](Mutator::unmutate)
+    /// uses to revert the mutation, and the complexity of the mutated value. The `other` value is left unchanged.
+    ///
+    /// Returns `None` if the two values cannot be combined. By default, values are never combined.
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut Value,
+        cache: &mut Self::Cache,
+        other: &Value,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        let _ = (value, cache, other, other_cache, max_cplx);
+        None
+    }
+
+    /// Describes, in a human-readable way, the mutation that was applied to the value and that can be reverted
+    /// with the given token.
+    ///
+    /// It is called on the mutated value, before it is unmutated. The fuzzer uses it to record the chain of
+    /// mutations that produced each test case added to the corpus and each failing test case. These traces are
+    /// saved in the stats folder.
+    ///
+    /// Describing a mutation is optional. By default, it returns `"unknown mutation"`.
+    #[no_coverage]
+    fn describe_mutation(&self, value: &Value, token: &Self::UnmutateToken) -> String {
+        let _ = (value, token);
+        String::from("unknown mutation")
+    }
+}
+
+/**
+ * A [Reporter] receives the events of the fuzzer and the messages it wants to show to the user.
+ *
+ * By default, the fuzzer uses a [`TerminalReporter`](crate::reporters::TerminalReporter), which
+ * prints them to stdout. A different reporter can be given to the fuzzer with
+ * [`FuzzerBuilder5::reporter`](crate::builder::FuzzerBuilder5::reporter).
+ *
+ * Note that events can be reported from the signal handler of the fuzzer, after which it terminates.
+*/
+pub trait Reporter: 'static {
+    /// Report an event of the fuzzer, along with the statistics of the fuzzer and of its pool, if available.
+    fn report_event(
+        &mut self,
+        event: FuzzerEvent,
+        time_since_start: Duration,
+        stats: Option<(&FuzzerStats, &dyn Stats)>,
+    );
+    /// Report a message that is not tied to an event, such as the location of a saved artifact.
+    ///
+    /// The message may span several lines.
+    fn report_message(&mut self, message: &str);
+    /// Report the outcome of the `replay` command.
+    ///
+    /// By default, the test cases that failed or timed out are listed in a message, followed by a summary.
+    #[no_coverage]
+    fn report_replay(&mut self, report: &ReplayReport) {
+        let mut message = String::new();
+        for (path, outcome) in &report.results {
+            match outcome {
+                ReplayOutcome::Failed(failure) => {
+                    message += &format!("FAILED {}\n", path.display());
+                    for line in failure.lines() {
+                        message += &format!("    {}\n", line);
+                    }
+                }
+                ReplayOutcome::TimedOut => message += &format!("TIMED OUT {}\n", path.display()),
+                ReplayOutcome::Passed | ReplayOutcome::Invalid => {}
+            }
+        }
+        message += &report.summary();
+        self.report_message(&message);
+    }
 }
 
 /**
@@ -516,6 +623,32 @@ where
         let m = self.wrapped_mutator();
         m.recursing_part::<V, N>(parent, value, index)
     }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn ordered_simplify(&self, value: &T, cache: &Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {
+        self.wrapped_mutator().ordered_simplify(value, cache, candidate)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn crossover_mutate(
+        &self,
+        value: &mut T,
+        cache: &mut Self::Cache,
+        other: &T,
+        other_cache: &Self::Cache,
+        max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        self.wrapped_mutator()
+            .crossover_mutate(value, cache, other, other_cache, max_cplx)
+    }
+
+    #[doc(hidden)]
+    #[no_coverage]
+    fn describe_mutation(&self, value: &T, token: &Self::UnmutateToken) -> String {
+        self.wrapped_mutator().describe_mutation(value, token)
+    }
 }
 
 impl<M> MutatorWrapper for Box<M> {
@@ -620,6 +753,14 @@ pub trait SensorAndPool: SaveToStatsFolder {
     fn stop_recording(&mut self);
     fn process(&mut self, input_id: PoolStorageIndex, cplx: f64) -> Vec<CorpusDelta>;
     fn get_random_index(&mut self) -> Option<PoolStorageIndex>;
+    /// See [`Pool::checkpoint`]
+    #[no_coverage]
+    fn checkpoint(&self) -> Vec<u8> {
+        vec![]
+    }
+    /// See [`Pool::restore_checkpoint`]
+    #[no_coverage]
+    fn restore_checkpoint(&mut self, _checkpoint: &[u8]) {}
 }
 impl<A, B> SaveToStatsFolder for (A, B)
 where
@@ -660,6 +801,14 @@ where
     fn get_random_index(&mut self) -> Option<PoolStorageIndex> {
         self.1.get_random_index()
     }
+    #[no_coverage]
+    fn checkpoint(&self) -> Vec<u8> {
+        self.1.checkpoint()
+    }
+    #[no_coverage]
+    fn restore_checkpoint(&mut self, checkpoint: &[u8]) {
+        self.1.restore_checkpoint(checkpoint)
+    }
 }
 
 pub enum CSVField {
@@ -729,6 +878,11 @@ The pool trait is divided into two parts:
 1. [`Pool`] contains general methods that are independent of the sensor used
 2. [`CompatibleWithObservations<O>`] is a subtrait of [`Pool`]. It describes how the pool handles
 observations made by the [`Sensor`].
+
+Like mutators, pools that need randomness should create their random number generator with
+`fastrand::Rng::new()` when they are constructed, after
+[`FuzzerBuilder1::seed`](crate::builder::FuzzerBuilder1::seed) is called, for the seed
+of the fuzzer to apply to them.
 */
 pub trait Pool: SaveToStatsFolder {
     /// Statistics about the pool to be printed to the terminal as the fuzzer is running and
@@ -750,6 +904,26 @@ pub trait Pool: SaveToStatsFolder {
     fn weight(&self) -> f64 {
         1.0
     }
+
+    /// Serialize the state of the pool that should survive when the fuzzing session is stopped and resumed later.
+    ///
+    /// The test cases of the pool are not part of this state. They are saved by the fuzzer and given
+    /// back to the pool, through [`CompatibleWithObservations::process`], before the state is restored
+    /// with [`self.restore_checkpoint(..)`](Pool::restore_checkpoint). Therefore, a pool only needs to save
+    /// the state that cannot be derived from its test cases and their observations.
+    ///
+    /// By default, the pool has no state to save. Among the pools provided by fuzzcheck, only
+    /// [`AndPool`](crate::sensors_and_pools::AndPool) and
+    /// [`AndSensorAndPool`](crate::sensors_and_pools::AndSensorAndPool) save a state: the number of times
+    /// each of their sub-pools was chosen since it last made progress.
+    #[no_coverage]
+    fn checkpoint(&self) -> Vec<u8> {
+        vec![]
+    }
+
+    /// Restore the state previously returned by [`self.checkpoint()`](Pool::checkpoint)
+    #[no_coverage]
+    fn restore_checkpoint(&mut self, _checkpoint: &[u8]) {}
 }
 
 pub trait PoolExt: Pool + Sized {
@@ -1,23 +1,32 @@
 use crate::fuzzer::PoolStorageIndex;
+use crate::fuzzer::ReplayReport;
 use crate::fuzzer::TerminationStatus;
+#[cfg(feature = "serde_json_serializer")]
+use crate::reporters::JsonReporter;
+use crate::sensors_and_pools::TestFailure;
 use crate::traits::CorpusDelta;
+use crate::traits::Reporter;
 use crate::traits::SaveToStatsFolder;
 use crate::traits::Stats;
 use crate::CSVField;
+use crate::MutationTrace;
 use crate::ToCSV;
 use fuzzcheck_common::arg::Arguments;
 use fuzzcheck_common::arg::FuzzerCommand;
+use fuzzcheck_common::arg::{EventStream, InvalidCorpusPolicy, INVALID_CORPUS_FLAG};
 use fuzzcheck_common::{FuzzerEvent, FuzzerStats};
-use nu_ansi_term::Color;
 use std::cell::RefCell;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::fs::OpenOptions;
 use std::hash::{Hash, Hasher};
 use std::io::Write;
 use std::io::{self, Result};
+use std::os::unix::io::FromRawFd;
 use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -41,19 +50,74 @@ impl ToCSV for FuzzerStats {
     }
 }
 
+/// The kind of problem revealed by an artifact.
+///
+/// It determines the subfolder of the artifacts folder where the artifact is saved.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactKind {
+    /// The test function failed or crashed
+    Failure,
+    /// The test function did not finish within the allowed time
+    Timeout,
+}
+
+/// The name of the subfolder where the quarantined files of a corpus are moved
+const REJECTED_FOLDER: &str = "rejected";
+
+/// The extension of the file holding the metadata of an artifact, which is saved next to it
+const ARTIFACT_METADATA_EXTENSION: &str = "meta.json";
+
+/// The name of the subfolder of the stats folder where the mutation traces of the test cases are saved
+const MUTATION_TRACES_FOLDER: &str = "mutation_traces";
+
+/// The prefix of the name under which a corpus file is written before being renamed, which is ignored when
+/// reading a corpus
+const TEMPORARY_FILE_PREFIX: &str = ".tmp-";
+
+/// The reason why a file of a corpus could not be used as a test case
+#[derive(Debug)]
+pub(crate) enum RejectionReason {
+    /// The file could not be read
+    Unreadable(io::Error),
+    /// The serializer could not decode the content of the file
+    Undecodable,
+    /// The mutator rejected the decoded value
+    Invalid,
+}
+impl fmt::Display for RejectionReason {
+    #[no_coverage]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RejectionReason::Unreadable(e) => write!(f, "the file could not be read ({})", e),
+            RejectionReason::Undecodable => write!(f, "the serializer could not decode it"),
+            RejectionReason::Invalid => write!(f, "the mutator rejected the decoded value"),
+        }
+    }
+}
+
 pub struct World {
     settings: Arguments,
     initial_instant: Instant,
     checkpoint_instant: Instant,
     /// keeps track of the hash of each input in the corpus, indexed by the Pool key
     pub corpus: HashMap<(PathBuf, PoolStorageIndex), String>,
+    /// the files of the input and output corpora that were already decoded or written by this fuzzer
+    seen_corpus_files: HashSet<PathBuf>,
+    /// the corpus files that could not be used as test cases, along with their modification time at that point.
+    /// They are only read again once they are modified, for example because they were still being written.
+    unusable_corpus_files: HashMap<PathBuf, Option<SystemTime>>,
+    /// the corpus files that could not be used as test cases and were not handled yet
+    rejected_corpus_files: Vec<(PathBuf, RejectionReason)>,
     pub stats: Option<RefCell<File>>,
     pub stats_folder: Option<PathBuf>,
+    reporter: RefCell<Box<dyn Reporter>>,
+    /// receives the events, to write them as JSON lines to the file given in the arguments
+    event_stream: Option<RefCell<Box<dyn Reporter>>>,
 }
 
 impl World {
     #[no_coverage]
-    pub fn new(settings: Arguments) -> Result<Self> {
+    pub fn new(settings: Arguments, reporter: Box<dyn Reporter>) -> Result<Self> {
         let (stats, stats_folder) = if let Some(stats_folder) = &settings.stats_folder {
             let now = SystemTime::now();
             let duration_since_epoch = now.duration_since(SystemTime::UNIX_EPOCH).unwrap();
@@ -66,18 +130,56 @@ impl World {
         } else {
             (None, None)
         };
+        let event_stream = if let Some(events) = &settings.events {
+            let file = match events {
+                EventStream::File(path) => OpenOptions::new().create(true).append(true).open(path)?,
+                EventStream::FileDescriptor(fd) => {
+                    // the file descriptor belongs to the caller, who may keep using it after the fuzzer
+                    // stops, so the stream is written to a duplicate of it that can be closed
+                    let fd = unsafe { libc::dup(*fd) };
+                    if fd < 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                    unsafe { File::from_raw_fd(fd) }
+                }
+            };
+            Some(RefCell::new(Self::event_stream_reporter(file)?))
+        } else {
+            None
+        };
         Ok(Self {
             settings,
             initial_instant: std::time::Instant::now(),
             checkpoint_instant: std::time::Instant::now(),
             corpus: HashMap::new(),
+            seen_corpus_files: HashSet::new(),
+            unusable_corpus_files: HashMap::new(),
+            rejected_corpus_files: vec![],
             stats,
             stats_folder,
+            reporter: RefCell::new(reporter),
+            event_stream,
         })
     }
 
     #[no_coverage]
-    fn hash(&self, input: &[u8]) -> String {
+    fn event_stream_reporter(file: File) -> Result<Box<dyn Reporter>> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "serde_json_serializer")] {
+                Ok(Box::new(JsonReporter::new(file)))
+            } else {
+                let _ = file;
+                Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "the event stream requires the serde_json_serializer feature",
+                ))
+            }
+        }
+    }
+
+    /// The name of the corpus entry or artifact holding the given serialized test case
+    #[no_coverage]
+    pub(crate) fn hash(&self, input: &[u8]) -> String {
         let mut hasher = DefaultHasher::new();
         input.hash(&mut hasher);
         let hash = hasher.finish();
@@ -94,13 +196,22 @@ impl World {
         Ok(())
     }
 
+    /// Add the test case to, and remove the given ones from, the output corpus according to the deltas of the pool.
+    ///
+    /// If the test case failed, `test_failure` describes the failure. The corpus entries saved by a
+    /// [`TestFailurePool`](crate::sensors_and_pools::TestFailurePool), whose path is
+    /// `<pool name>/<failure id>/<complexity>`, then get a `<name>.meta.json` file describing the failure, like
+    /// the artifacts.
     #[no_coverage]
     pub(crate) fn update_corpus(
         &mut self,
         idx: PoolStorageIndex,
         content: Vec<u8>,
+        cplx: f64,
         deltas: &[CorpusDelta],
         extension: &str,
+        test_failure: Option<&TestFailure>,
+        total_number_of_runs: usize,
     ) -> Result<()> {
         for delta in deltas {
             let CorpusDelta { path, add, remove } = delta;
@@ -113,6 +224,20 @@ impl World {
                 let hash = self.hash(&content);
                 let _old = self.corpus.insert((path.to_path_buf(), idx), hash.clone());
                 self.add_to_output_corpus(path, hash.clone(), content.clone(), extension)?;
+                if let (Some(corpus_out), Some(test_failure)) = (&self.settings.corpus_out, test_failure) {
+                    let failure_id = test_failure.id.to_string();
+                    let is_test_failure_entry = path.parent().and_then(Path::file_name) == Some(failure_id.as_ref());
+                    if is_test_failure_entry {
+                        let entry = corpus_out.join(path).join(hash).with_extension(extension);
+                        self.save_artifact_metadata(
+                            &entry,
+                            cplx,
+                            ArtifactKind::Failure,
+                            Some(test_failure),
+                            total_number_of_runs,
+                        )?;
+                    }
+                }
             }
         }
 
@@ -120,7 +245,7 @@ impl World {
     }
 
     #[no_coverage]
-    pub fn add_to_output_corpus(&self, path: &Path, name: String, content: Vec<u8>, extension: &str) -> Result<()> {
+    pub fn add_to_output_corpus(&mut self, path: &Path, name: String, content: Vec<u8>, extension: &str) -> Result<()> {
         if self.settings.corpus_out.is_none() {
             return Ok(());
         }
@@ -130,8 +255,14 @@ impl World {
             std::fs::create_dir_all(&folder)?;
         }
 
-        let path = folder.join(name).with_extension(extension);
-        fs::write(path, content)?;
+        let path = folder.join(&name).with_extension(extension);
+        // other fuzzers may read the output corpus at any time, so they must never see a partially written file
+        let temporary_path = folder
+            .join(format!("{}{}", TEMPORARY_FILE_PREFIX, name))
+            .with_extension(extension);
+        fs::write(&temporary_path, content)?;
+        fs::rename(&temporary_path, &path)?;
+        self.seen_corpus_files.insert(path);
 
         Ok(())
     }
@@ -144,6 +275,7 @@ impl World {
         let corpus = self.settings.corpus_out.as_ref().unwrap().as_path().join(path);
 
         let path = corpus.join(name).with_extension(extension);
+        let _ = fs::remove_file(path.with_extension(ARTIFACT_METADATA_EXTENSION));
         let _ = fs::remove_file(path);
 
         Ok(())
@@ -151,84 +283,40 @@ impl World {
 
     #[no_coverage]
     pub(crate) fn report_event(&self, event: FuzzerEvent, stats: Option<(&FuzzerStats, &dyn Stats)>) {
-        // println uses a lock, which may mess up the signal handling
         let time_since_start = self.initial_instant.elapsed();
-        let time_since_start_display = {
-            let time_since_start_millis = time_since_start.as_millis();
-            if time_since_start_millis > 10_000 {
-                let time_since_start_seconds = time_since_start.as_secs();
-                format!("{}s ", time_since_start_seconds)
-            } else {
-                format!("{}ms ", time_since_start_millis)
-            }
-        };
-        print!("{} ", time_since_start_display);
-        match event {
-            FuzzerEvent::Start => {
-                println!("{}", Color::Yellow.paint("START"));
-                return;
-            }
-            FuzzerEvent::Pulse => {
-                print!("{} ", Color::Yellow.paint("PULSE"));
-            }
-            FuzzerEvent::Stop => {
-                println!("\n======================== STOPPED ========================");
-                println!(r#"The fuzzer was stopped."#);
-                return;
-            }
-            FuzzerEvent::End => {
-                println!("\n======================== END ========================");
-                println!(
-                    r#"Fuzzcheck cannot generate more arbitrary values of the input type. This may be
-because all possible values under the chosen maximum complexity were tested, or
-because the mutator does not know how to generate more values."#
-                );
-                return;
-            }
-            FuzzerEvent::CrashNoInput => {
-                println!("\n=================== CRASH DETECTED ===================");
-                println!(
-                    r#"A crash was detected, but the fuzzer cannot recover the crashing input.
-This should never happen, and is probably a bug in fuzzcheck. Sorry :("#
-                );
-                return;
-            }
-            FuzzerEvent::Done => {
-                println!("{}", Color::Yellow.paint("DONE"));
-                return;
-            }
-            FuzzerEvent::DidReadCorpus => {
-                println!("{}", Color::Yellow.paint("FINISHED READING CORPUS"));
-                return;
-            }
-            FuzzerEvent::CaughtSignal(signal) => println!("\n================ SIGNAL {} ================", signal),
-
-            FuzzerEvent::TestFailure => {
-                println!("\n================ TEST FAILED ================");
-            }
-            FuzzerEvent::Replace(_, _) => {}
-            FuzzerEvent::None => return,
-        };
+        self.reporter
+            .try_borrow_mut()
+            .unwrap()
+            .report_event(event, time_since_start, stats);
+        if let Some(event_stream) = &self.event_stream {
+            event_stream
+                .try_borrow_mut()
+                .unwrap()
+                .report_event(event, time_since_start, stats);
+        }
         if let Some((fuzzer_stats, pool_stats)) = stats {
-            print!(
-                "{} ",
-                Color::Yellow.paint(format!("{}", fuzzer_stats.total_number_of_runs))
-            );
-            print!("{} ", Color::Yellow.paint(format!("{}", pool_stats)));
-            print!(
-                "{} ",
-                Color::Yellow.paint(format!("iter/s {}", fuzzer_stats.exec_per_s))
-            );
-
-            println!();
-            let mut stats_fields = vec![CSVField::Integer(time_since_start.as_millis() as isize)];
-            stats_fields.extend(fuzzer_stats.to_csv_record());
-            stats_fields.extend(pool_stats.to_csv_record());
-            self.append_stats_file(&stats_fields)
-                .expect("cannot write to stats file");
+            if matches!(
+                event,
+                FuzzerEvent::Pulse
+                    | FuzzerEvent::CaughtSignal(_)
+                    | FuzzerEvent::TestFailure
+                    | FuzzerEvent::Timeout
+                    | FuzzerEvent::Replace(_, _)
+            ) {
+                let mut stats_fields = vec![CSVField::Integer(time_since_start.as_millis() as isize)];
+                stats_fields.extend(fuzzer_stats.to_csv_record());
+                stats_fields.extend(pool_stats.to_csv_record());
+                self.append_stats_file(&stats_fields)
+                    .expect("cannot write to stats file");
+            }
         }
     }
 
+    #[no_coverage]
+    pub(crate) fn report_message(&self, message: &str) {
+        self.reporter.try_borrow_mut().unwrap().report_message(message);
+    }
+
     // #[no_coverage]
     // pub fn set_start_instant(&mut self) {
     //     self.initial_instant = Instant::now();
@@ -247,17 +335,37 @@ This should never happen, and is probably a bug in fuzzcheck. Sorry :("#
     }
 
     #[no_coverage]
-    pub fn read_input_corpus(&self) -> Result<Vec<Vec<u8>>> {
+    pub fn read_input_corpus(&mut self) -> Result<Vec<(PathBuf, Vec<u8>)>> {
         if self.settings.corpus_in.is_none() {
             return Result::Ok(vec![]);
         }
-        let corpus = self.settings.corpus_in.as_ref().unwrap().as_path();
+        let corpus = self.settings.corpus_in.clone().unwrap();
+        self.read_corpus_files(&corpus)
+    }
+    /// Read the files of the output corpus that were not read or written by this fuzzer yet.
+    ///
+    /// They are typically test cases found by other fuzzers sharing the same output corpus.
+    #[no_coverage]
+    pub(crate) fn read_new_output_corpus_files(&mut self) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+        if self.settings.corpus_out.is_none() {
+            return Result::Ok(vec![]);
+        }
+        let corpus = self.settings.corpus_out.clone().unwrap();
+        self.read_corpus_files(&corpus)
+    }
+    /// Read the files of the given corpus that were not read or written by this fuzzer yet,
+    /// along with their paths
+    #[no_coverage]
+    pub(crate) fn read_corpus_files(&mut self, corpus: &Path) -> Result<Vec<(PathBuf, Vec<u8>)>> {
         let mut values = vec![];
         self.read_input_corpus_rec(corpus, &mut values)?;
         Ok(values)
     }
+    /// Read the files of the given folder, recursively, ignoring the ones that were already seen,
+    /// the unusable ones that were not modified since, the quarantined ones, the temporary ones, and the metadata
+    /// of artifacts. The files that cannot be read are rejected.
     #[no_coverage]
-    fn read_input_corpus_rec(&self, corpus: &Path, values: &mut Vec<Vec<u8>>) -> Result<()> {
+    fn read_input_corpus_rec(&mut self, corpus: &Path, values: &mut Vec<(PathBuf, Vec<u8>)>) -> Result<()> {
         if !corpus.exists() {
             return Ok(());
         }
@@ -267,60 +375,247 @@ This should never happen, and is probably a bug in fuzzcheck. Sorry :("#
                 "The corpus path is not a directory.",
             ));
         }
-        for entry in fs::read_dir(corpus)? {
-            let entry = entry?;
-            let path = entry.path();
+        // the files are read in a fixed order so that a fuzzer run can be reproduced with the same seed
+        let mut paths = fs::read_dir(corpus)?
+            .map(
+                #[no_coverage]
+                |entry| {
+                    entry.map(
+                        #[no_coverage]
+                        |entry| entry.path(),
+                    )
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+        paths.sort();
+        for path in paths {
             if path.is_dir() {
-                self.read_input_corpus_rec(&path, values)?;
-            } else {
-                let data = fs::read(path)?;
-                values.push(data);
+                if path.file_name() != Some(REJECTED_FOLDER.as_ref()) {
+                    self.read_input_corpus_rec(&path, values)?;
+                }
+            } else if !self.seen_corpus_files.contains(&path)
+                && !is_artifact_metadata(&path)
+                && !is_temporary_file(&path)
+                && !self.is_unusable_corpus_file(&path)
+            {
+                match fs::read(&path) {
+                    Ok(data) => values.push((path, data)),
+                    Err(e) => self.reject_corpus_file(path, RejectionReason::Unreadable(e)),
+                }
             }
         }
         Ok(())
     }
 
+    /// Record that the given corpus file was decoded into a test case, so that it is not read again
+    #[no_coverage]
+    pub(crate) fn accept_corpus_file(&mut self, path: PathBuf) {
+        self.unusable_corpus_files.remove(&path);
+        self.seen_corpus_files.insert(path);
+    }
+
+    /// Record that the given corpus file could not be used as a test case.
+    ///
+    /// It is handled by the next call to [`self.handle_rejected_corpus_files()`](World::handle_rejected_corpus_files)
+    /// and is not read again unless it is modified.
+    #[no_coverage]
+    pub(crate) fn reject_corpus_file(&mut self, path: PathBuf, reason: RejectionReason) {
+        self.unusable_corpus_files
+            .insert(path.clone(), modification_time(&path));
+        self.rejected_corpus_files.push((path, reason));
+    }
+
+    /// Whether the given corpus file was rejected before and was not modified since
+    #[no_coverage]
+    fn is_unusable_corpus_file(&self, path: &Path) -> bool {
+        self.unusable_corpus_files.get(path).map_or(
+            false,
+            #[no_coverage]
+            |modified| *modified == modification_time(path),
+        )
+    }
+
+    /// Handle the corpus files rejected since the last call according to the
+    /// [`InvalidCorpusPolicy`] given in the arguments.
+    ///
+    /// Returns `true` if the fuzzer must stop because of them, i.e. if some files were rejected and
+    /// the policy is [`InvalidCorpusPolicy::Fail`].
+    #[no_coverage]
+    pub(crate) fn handle_rejected_corpus_files(&mut self) -> Result<bool> {
+        let rejected = std::mem::take(&mut self.rejected_corpus_files);
+        let policy = self.settings.invalid_corpus;
+        if rejected.is_empty() || policy == InvalidCorpusPolicy::Ignore {
+            return Ok(false);
+        }
+        let mut message = format!("{} CORPUS FILES WERE REJECTED", rejected.len());
+        for (path, reason) in &rejected {
+            if policy == InvalidCorpusPolicy::Quarantine && path.exists() {
+                let folder = path.parent().unwrap().join(REJECTED_FOLDER);
+                fs::create_dir_all(&folder)?;
+                let destination = folder.join(path.file_name().unwrap());
+                fs::rename(path, &destination)?;
+                message += &format!(
+                    "\n    {}: {}, moved to {}",
+                    path.display(),
+                    reason,
+                    destination.display()
+                );
+            } else {
+                message += &format!("\n    {}: {}", path.display(), reason);
+            }
+        }
+        if policy == InvalidCorpusPolicy::Fail {
+            message += &format!("\nUse --{} to skip them.", INVALID_CORPUS_FLAG);
+        }
+        self.report_message(&message);
+        Ok(policy == InvalidCorpusPolicy::Fail)
+    }
+
+    #[no_coverage]
+    pub(crate) fn report_replay(&self, report: &ReplayReport) {
+        self.reporter.try_borrow_mut().unwrap().report_replay(report);
+    }
+
     #[no_coverage]
     pub fn read_input_file(&self, file: &Path) -> Result<Vec<u8>> {
         let data = fs::read(file)?;
         Ok(data)
     }
 
+    /// Save the given test case to the artifacts folder.
+    ///
+    /// Unless the fuzzer is minifying a test case, a `<name>.meta.json` file describing the test failure is
+    /// saved next to it, which requires the `serde_json_serializer` feature.
     #[no_coverage]
-    pub fn save_artifact(&mut self, content: Vec<u8>, cplx: f64, extension: &str) -> Result<()> {
+    pub fn save_artifact(
+        &mut self,
+        content: Vec<u8>,
+        cplx: f64,
+        kind: ArtifactKind,
+        extension: &str,
+        test_failure: Option<&TestFailure>,
+        total_number_of_runs: usize,
+    ) -> Result<()> {
         let artifacts_folder = self.settings.artifacts_folder.as_ref();
         if artifacts_folder.is_none() {
             return Ok(());
         }
         let artifacts_folder = artifacts_folder.unwrap().as_path();
+        let is_minifying = matches!(
+            self.settings.command,
+            FuzzerCommand::MinifyInput { .. } | FuzzerCommand::Read { .. }
+        );
+        // when minifying, all artifacts must stay in the same folder so that cargo-fuzzcheck can find the simplest one
+        let artifacts_folder = match kind {
+            ArtifactKind::Timeout if !is_minifying => artifacts_folder.join("timeout"),
+            _ => artifacts_folder.to_path_buf(),
+        };
 
         if !artifacts_folder.is_dir() {
-            std::fs::create_dir_all(artifacts_folder)?;
+            std::fs::create_dir_all(&artifacts_folder)?;
         }
 
         let mut hasher = DefaultHasher::new();
         content.hash(&mut hasher);
         let hash = hasher.finish();
 
-        let name = if let FuzzerCommand::MinifyInput { .. } | FuzzerCommand::Read { .. } = self.settings.command {
+        let name = if is_minifying {
             format!("{:.0}--{:x}", cplx * 100.0, hash)
         } else {
             format!("{:x}", hash)
         };
 
         let path = artifacts_folder.join(&name).with_extension(extension);
-        println!("Failing test case found. Saving at {:?}", path);
-        fs::write(path, &content)?;
+        match kind {
+            ArtifactKind::Failure => self.report_message(&format!("Failing test case found. Saving at {:?}", path)),
+            ArtifactKind::Timeout => {
+                self.report_message(&format!("Test case exceeding the timeout found. Saving at {:?}", path))
+            }
+        }
+        fs::write(&path, &content)?;
+        if !is_minifying {
+            self.save_artifact_metadata(&path, cplx, kind, test_failure, total_number_of_runs)?;
+        }
 
         Result::Ok(())
     }
 
+    #[no_coverage]
+    fn save_artifact_metadata(
+        &self,
+        artifact: &Path,
+        cplx: f64,
+        kind: ArtifactKind,
+        test_failure: Option<&TestFailure>,
+        total_number_of_runs: usize,
+    ) -> Result<()> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "serde_json_serializer")] {
+                let settings = &self.settings;
+                let discovered_at = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+                let metadata = serde_json::json!({
+                    "kind": match kind {
+                        ArtifactKind::Failure => "failure",
+                        ArtifactKind::Timeout => "timeout",
+                    },
+                    "message": test_failure.map(#[no_coverage] |f| &f.display),
+                    "failure_id": test_failure.map(#[no_coverage] |f| f.id),
+                    "complexity": cplx,
+                    "backtrace": test_failure.map(#[no_coverage] |f| &f.frames),
+                    "discovered_at_ms": discovered_at.as_millis() as u64,
+                    "elapsed_time_ms": self.elapsed_time_since_start().as_millis() as u64,
+                    "iteration": total_number_of_runs,
+                    "seed": settings.seed,
+                    "arguments": {
+                        "command": format!("{:?}", settings.command),
+                        "max_cplx": settings.max_input_cplx,
+                        "timeout_ms": settings.timeout.map(#[no_coverage] |t| t.as_millis() as u64),
+                        "stop_after_first_failure": settings.stop_after_first_failure,
+                        "fork": settings.fork,
+                        "jobs": settings.jobs,
+                        "backtrace_frames": settings.backtrace_frames,
+                        "in_corpus": settings.corpus_in,
+                        "out_corpus": settings.corpus_out,
+                        "artifacts": settings.artifacts_folder,
+                        "stats": settings.stats_folder,
+                        "resume": settings.resume,
+                    },
+                });
+                let content = serde_json::to_vec_pretty(&metadata).unwrap();
+                fs::write(artifact.with_extension(ARTIFACT_METADATA_EXTENSION), content)
+            } else {
+                let _ = (artifact, cplx, kind, test_failure, total_number_of_runs);
+                Ok(())
+            }
+        }
+    }
+
     #[no_coverage]
     pub fn stop(&mut self) -> ! {
         self.report_event(FuzzerEvent::Stop, None);
         std::process::exit(TerminationStatus::Success as i32);
     }
 
+    /// Whether the mutation traces of the test cases are saved, which is the case when a stats folder is used
+    #[no_coverage]
+    pub(crate) fn saves_mutation_traces(&self) -> bool {
+        self.stats_folder.is_some()
+    }
+
+    /// Save the mutation trace of a test case in the `mutation_traces` folder of the stats folder, in a text file
+    /// with the same name as the corpus entry or artifact holding the test case.
+    #[no_coverage]
+    pub(crate) fn save_mutation_trace(&self, name: &str, trace: &MutationTrace) -> Result<()> {
+        if let Some(stats_folder) = &self.stats_folder {
+            let folder = stats_folder.join(MUTATION_TRACES_FOLDER);
+            if !folder.is_dir() {
+                std::fs::create_dir_all(&folder)?;
+            }
+            fs::write(folder.join(name).with_extension("txt"), trace.to_string())?;
+        }
+        Ok(())
+    }
+
     #[no_coverage]
     pub fn write_stats_content(&self, contents: Vec<(PathBuf, Vec<u8>)>) -> Result<()> {
         if let Some(stats_folder) = &self.stats_folder {
@@ -332,6 +627,37 @@ This should never happen, and is probably a bug in fuzzcheck. Sorry :("#
         Ok(())
     }
 }
+/// Whether the given file is a corpus file that is still being written, see [`World::add_to_output_corpus`]
+#[no_coverage]
+fn is_temporary_file(path: &Path) -> bool {
+    path.file_name().and_then(std::ffi::OsStr::to_str).map_or(
+        false,
+        #[no_coverage]
+        |name| name.starts_with(TEMPORARY_FILE_PREFIX),
+    )
+}
+
+/// The time at which the given file was last modified, if it can be known
+#[no_coverage]
+fn modification_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(
+            #[no_coverage]
+            |metadata| metadata.modified(),
+        )
+        .ok()
+}
+
+/// Whether the given file holds the metadata of an artifact, rather than a test case
+#[no_coverage]
+fn is_artifact_metadata(path: &Path) -> bool {
+    path.to_str().map_or(
+        false,
+        #[no_coverage]
+        |path| path.ends_with(ARTIFACT_METADATA_EXTENSION),
+    )
+}
+
 impl SaveToStatsFolder for World {
     #[no_coverage]
     fn save_to_stats_folder(&self) -> Vec<(PathBuf, Vec<u8>)> {
@@ -345,3 +671,117 @@ impl SaveToStatsFolder for World {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{RejectionReason, World};
+    use crate::reporters::QuietReporter;
+    use fuzzcheck_common::arg::{
+        options_parser, Arguments, INVALID_CORPUS_FAIL, INVALID_CORPUS_IGNORE, INVALID_CORPUS_QUARANTINE,
+        INVALID_CORPUS_WARN,
+    };
+    use std::path::{Path, PathBuf};
+
+    #[no_coverage]
+    fn world_with_output_corpus(name: &str) -> (World, PathBuf) {
+        let folder = std::env::temp_dir().join(format!("fuzzcheck-world-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        let matches = options_parser()
+            .parse(["--out-corpus", folder.to_str().unwrap()])
+            .unwrap();
+        let settings = Arguments::from_matches(&matches, false).unwrap();
+        (World::new(settings, Box::new(QuietReporter)).unwrap(), folder)
+    }
+
+    #[no_coverage]
+    fn paths(files: Vec<(PathBuf, Vec<u8>)>) -> Vec<PathBuf> {
+        files.into_iter().map(|(path, _)| path).collect()
+    }
+
+    #[test]
+    fn test_add_to_output_corpus() {
+        let (mut world, folder) = world_with_output_corpus("add");
+        world
+            .add_to_output_corpus(Path::new("pool"), "a".to_owned(), b"abc".to_vec(), "bin")
+            .unwrap();
+        let entries = std::fs::read_dir(folder.join("pool"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(entries, ["a.bin"]);
+        assert_eq!(std::fs::read(folder.join("pool/a.bin")).unwrap(), b"abc");
+        // the files written by the fuzzer itself are not read again
+        assert!(world.read_new_output_corpus_files().unwrap().is_empty());
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn test_read_corpus_files_until_accepted() {
+        let (mut world, folder) = world_with_output_corpus("read");
+        std::fs::create_dir_all(folder.join("pool")).unwrap();
+        let file = folder.join("pool/b.bin");
+        std::fs::write(&file, b"partial").unwrap();
+        std::fs::write(folder.join("pool/.tmp-c.bin"), b"partial").unwrap();
+
+        assert_eq!(paths(world.read_new_output_corpus_files().unwrap()), [file.clone()]);
+        // a rejected file is read again once it is modified, for example because it was still being written
+        world.reject_corpus_file(file.clone(), RejectionReason::Undecodable);
+        assert!(world.read_new_output_corpus_files().unwrap().is_empty());
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        std::fs::write(&file, b"complete").unwrap();
+        assert_eq!(paths(world.read_new_output_corpus_files().unwrap()), [file.clone()]);
+        // but an accepted file is never read again
+        world.accept_corpus_file(file);
+        assert!(world.read_new_output_corpus_files().unwrap().is_empty());
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn test_handle_rejected_corpus_files() {
+        for (policy, stops, quarantined) in [
+            (INVALID_CORPUS_IGNORE, false, false),
+            (INVALID_CORPUS_WARN, false, false),
+            (INVALID_CORPUS_QUARANTINE, false, true),
+            (INVALID_CORPUS_FAIL, true, false),
+        ] {
+            let folder =
+                std::env::temp_dir().join(format!("fuzzcheck-world-rejected-{}-{}", policy, std::process::id()));
+            let _ = std::fs::remove_dir_all(&folder);
+            std::fs::create_dir_all(&folder).unwrap();
+            let file = folder.join("a.bin");
+            std::fs::write(&file, b"invalid").unwrap();
+            let matches = options_parser()
+                .parse(["--in-corpus", folder.to_str().unwrap(), "--invalid-corpus", policy])
+                .unwrap();
+            let settings = Arguments::from_matches(&matches, false).unwrap();
+            let mut world = World::new(settings, Box::new(QuietReporter)).unwrap();
+
+            world.reject_corpus_file(file.clone(), RejectionReason::Undecodable);
+            assert_eq!(world.handle_rejected_corpus_files().unwrap(), stops, "{}", policy);
+            assert_eq!(file.exists(), !quarantined, "{}", policy);
+            assert_eq!(folder.join("rejected/a.bin").exists(), quarantined, "{}", policy);
+            // the rejected files are only handled once
+            assert!(!world.handle_rejected_corpus_files().unwrap());
+            let _ = std::fs::remove_dir_all(&folder);
+        }
+    }
+
+    #[test]
+    fn test_events_file_descriptor_stays_open() {
+        let mut fds = [0; 2];
+        assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+        let fd = fds[1].to_string();
+        let matches = options_parser().parse(["--events-fd", fd.as_str()]).unwrap();
+        let settings = Arguments::from_matches(&matches, false).unwrap();
+        drop(World::new(settings, Box::new(QuietReporter)).unwrap());
+        // the file descriptor given to the fuzzer is still open, and can still be written to
+        assert_eq!(
+            unsafe { libc::write(fds[1], b"x".as_ptr() as *const libc::c_void, 1) },
+            1
+        );
+        unsafe {
+            libc::close(fds[0]);
+            libc::close(fds[1]);
+        }
+    }
+}
@@ -22,3 +22,30 @@ fn test_alternation_char() {
     // a possibility for all three first choices.
     // test_alternation_char_helper(['a'..='z', 'a'..='b', 'a'..='c', '0'..='9', '0'..='5']);
 }
+
+#[test]
+fn test_alternation_ordered_simplify() {
+    use fuzzcheck::Mutator;
+
+    let m = AlternationMutator::new(vec![
+        CharWithinRangeMutator::new('a'..='z'),
+        CharWithinRangeMutator::new('0'..='9'),
+    ]);
+    let cache = m.validate_value(&'q').unwrap();
+    let candidates = || {
+        let mut candidates = vec![];
+        m.ordered_simplify(&'q', &cache, &mut |candidate| {
+            candidates.push(candidate);
+            false
+        });
+        candidates
+    };
+    // the only candidate is the first value generated by the simpler alternative, which 'q' does not belong to,
+    // so it is always the same
+    let first = candidates();
+    assert_eq!(first.len(), 1);
+    assert!(first[0].is_ascii_digit());
+    for _ in 0..10 {
+        assert_eq!(candidates(), first);
+    }
+}
@@ -6,7 +6,7 @@ use fuzzcheck::mutators::boxed::BoxMutator;
 use fuzzcheck::mutators::integer::U8Mutator;
 use fuzzcheck::mutators::option::OptionMutator;
 use fuzzcheck::mutators::recursive::RecurToMutator;
-use fuzzcheck::mutators::testing_utilities::test_mutator;
+use fuzzcheck::mutators::testing_utilities::{test_crossover, test_mutator};
 use fuzzcheck::mutators::tuples::{Tuple2, Tuple2Mutator, TupleMutatorWrapper};
 use fuzzcheck::mutators::vector::VecMutator;
 use fuzzcheck::DefaultMutator;
@@ -70,3 +70,9 @@ fn test_derived_struct() {
     let mutator = <Vec<SampleStruct<u8, u8>>>::default_mutator();
     test_mutator(mutator, 500., 500., false, true, 50, 100);
 }
+
+#[test]
+fn test_derived_recursive_struct_crossover() {
+    let mutator = SampleStruct::<u8, u8>::default_mutator();
+    assert!(test_crossover(&mutator, 500., 100) > 0);
+}
@@ -1,6 +1,6 @@
 #![feature(no_coverage)]
 
-use fuzzcheck::mutators::testing_utilities::test_mutator;
+use fuzzcheck::mutators::testing_utilities::{test_crossover, test_mutator};
 use fuzzcheck::DefaultMutator;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, DefaultMutator)]
@@ -16,3 +16,11 @@ fn test_derived_struct() {
     let mutator = <Vec<SampleStruct<u8, u8>>>::default_mutator();
     test_mutator(mutator, 1000., 1000., false, true, 100, 100);
 }
+
+#[test]
+fn test_derived_struct_crossover() {
+    let mutator = SampleStruct::<u8, u8>::default_mutator();
+    assert!(test_crossover(&mutator, 1000., 100) > 0);
+    let mutator = <Vec<SampleStruct<u8, u8>>>::default_mutator();
+    assert!(test_crossover(&mutator, 1000., 100) > 0);
+}
@@ -0,0 +1,982 @@
+#![feature(no_coverage)]
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use fuzzcheck::builder::FuzzTestFunction;
+use fuzzcheck::mutators::integer::U8Mutator;
+use fuzzcheck::mutators::vector::VecMutator;
+use fuzzcheck::reporters::QuietReporter;
+use fuzzcheck::sensors_and_pools::{ArrayOfCounters, SimplestToActivateCounterPool};
+use fuzzcheck::serializers::ByteSerializer;
+use fuzzcheck::{Arguments, FuzzingResult, Mutator, ReasonForStopping, ReplayOutcome, SensorExt};
+use fuzzcheck_common::arg::options_parser;
+
+/// The fuzzer relies on global state, such as the panic hook and the counters below, so the fuzz tests of this
+/// file run one at a time
+static FUZZER_LOCK: Mutex<()> = Mutex::new(());
+
+static mut COUNTERS: [u64; 4] = [0; 4];
+
+fn lock_fuzzer() -> MutexGuard<'static, ()> {
+    FUZZER_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// An empty folder, unique to this process, for the files written by the fuzz test `name`
+fn test_folder(name: &str) -> PathBuf {
+    let folder = std::env::temp_dir().join(format!("fuzzcheck-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&folder);
+    std::fs::create_dir_all(&folder).unwrap();
+    folder
+}
+
+fn arguments(args: &[&str]) -> Arguments {
+    let matches = options_parser().parse(args).unwrap();
+    Arguments::from_matches(&matches, false).unwrap()
+}
+
+/// Record which features of the test case are present, so that the fuzzer has some progress to make
+fn observe(x: &[u8]) {
+    unsafe {
+        COUNTERS[0] = x.len() as u64;
+        COUNTERS[1] = x.iter().filter(|&&b| b == 0).count() as u64;
+        COUNTERS[2] = x.first().map_or(0, |&b| b as u64);
+        COUNTERS[3] = x.windows(2).filter(|w| w[0] < w[1]).count() as u64;
+    }
+}
+
+/// Launch a fuzzer for vectors of bytes, guided by the counters set by `observe`, with the given arguments
+fn fuzz<F, Kind>(test: F, args: &[&str]) -> FuzzingResult<Vec<u8>>
+where
+    F: FuzzTestFunction<Vec<u8>, [u8], Kind>,
+    F::NormalizedFunction: 'static,
+{
+    fuzz_with_mutator(|| VecMutator::new(U8Mutator::default(), 0..=16), test, args)
+}
+
+/// Same as `fuzz`, but with the mutator returned by `mutator`, which is only created once the builder is seeded
+fn fuzz_with_mutator<M, F, Kind>(mutator: impl FnOnce() -> M, test: F, args: &[&str]) -> FuzzingResult<Vec<u8>>
+where
+    M: Mutator<Vec<u8>>,
+    F: FuzzTestFunction<Vec<u8>, [u8], Kind>,
+    F::NormalizedFunction: 'static,
+{
+    let arguments = arguments(args);
+    let mut builder = fuzzcheck::fuzz_test(test);
+    if let Some(seed) = arguments.seed {
+        builder = builder.seed(seed);
+    }
+    let sensor = ArrayOfCounters::new(unsafe { &mut COUNTERS }).map(|o| {
+        o.iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, c)| *c != 0)
+            .collect::<Vec<_>>()
+    });
+    let pool = SimplestToActivateCounterPool::new("simplest_to_activate", 4);
+    builder
+        .mutator(mutator())
+        .serializer(ByteSerializer::new("bin"))
+        .sensor_and_pool(sensor, pool)
+        .arguments(arguments)
+        .reporter(QuietReporter)
+        .launch_even_if_cfg_fuzzing_is_not_set()
+}
+
+/// The paths of the files inside `folder` and its subfolders, relative to `folder` and sorted
+fn files_in(folder: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    let mut folders = vec![folder.to_path_buf()];
+    while let Some(f) = folders.pop() {
+        let Ok(entries) = std::fs::read_dir(&f) else { continue };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                folders.push(path);
+            } else {
+                files.push(path.strip_prefix(folder).unwrap().to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+#[cfg(feature = "serde_json_serializer")]
+#[test]
+fn test_failure_corpus_entries_have_metadata() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("failure-metadata");
+    let out_corpus = folder.join("out_corpus");
+    let result = fuzz(
+        |x: &[u8]| {
+            observe(x);
+            assert!(x.len() < 3, "the test case is too long");
+            true
+        },
+        &[
+            "--out-corpus",
+            out_corpus.to_str().unwrap(),
+            "--stop-after-iterations",
+            "2000",
+            "--seed",
+            "1",
+        ],
+    );
+    assert!(result.found_test_failure);
+
+    let failures = out_corpus.join("test_failures");
+    let entries = files_in(&failures)
+        .into_iter()
+        .filter(|path| path.extension().unwrap() == "bin")
+        .collect::<Vec<_>>();
+    assert!(!entries.is_empty());
+    for entry in entries {
+        // the path of an entry is <failure id>/<complexity>/<hash>.bin
+        let mut components = entry.iter().map(|c| c.to_str().unwrap());
+        let failure_id = components.next().unwrap();
+        let cplx = components.next().unwrap();
+
+        let metadata = std::fs::read(failures.join(&entry).with_extension("meta.json")).unwrap();
+        let metadata: serde_json::Value = serde_json::from_slice(&metadata).unwrap();
+        assert_eq!(metadata["kind"], "failure");
+        assert_eq!(metadata["failure_id"].as_u64().unwrap().to_string(), failure_id);
+        assert!(metadata["message"]
+            .as_str()
+            .unwrap()
+            .contains("the test case is too long"));
+        assert_eq!(format!("{:.4}", metadata["complexity"].as_f64().unwrap()), cplx);
+        assert_eq!(metadata["seed"], 1);
+        assert!(metadata["iteration"].as_u64().unwrap() <= 2000);
+    }
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+/// The number of iterations, the number of arbitrary test cases, the test cases along with their number of
+/// mutations, and the pool state saved in the checkpoint of the given session
+#[allow(clippy::type_complexity)]
+fn read_checkpoint(session: &Path) -> (u64, u64, Vec<(Vec<u8>, u64)>, Vec<u8>) {
+    let content = std::fs::read(session.join("checkpoint.bin")).unwrap();
+    let (runs, arbitrary_inputs, inputs, pool_state) =
+        bincode::deserialize::<(u64, u64, Vec<(Vec<u8>, u64, u64)>, Vec<u8>)>(&content).unwrap();
+    let mut inputs = inputs
+        .into_iter()
+        .map(|(input, mutations, _)| (input, mutations))
+        .collect::<Vec<_>>();
+    inputs.sort();
+    (runs, arbitrary_inputs, inputs, pool_state)
+}
+
+/// The folders created by the fuzzer for each session inside the stats folder, from the oldest to the newest
+fn sessions(stats: &Path) -> Vec<PathBuf> {
+    let mut sessions = std::fs::read_dir(stats)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    sessions.sort_by_key(|path| path.file_name().unwrap().to_str().unwrap().parse::<u128>().unwrap());
+    sessions
+}
+
+#[cfg(feature = "serde_json_serializer")]
+#[test]
+fn test_failure_backtrace_only_has_tested_crate_frames() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("failure-backtrace");
+    let out_corpus = folder.join("out_corpus");
+    let result = fuzz(
+        |x: &[u8]| {
+            observe(x);
+            assert!(x.len() < 3, "the test case is too long");
+            true
+        },
+        &[
+            "--out-corpus",
+            out_corpus.to_str().unwrap(),
+            "--backtrace-frames",
+            "4",
+            "--stop-after-iterations",
+            "2000",
+            "--seed",
+            "1",
+        ],
+    );
+    assert!(result.found_test_failure);
+    let failures = out_corpus.join("test_failures");
+    let metadata = files_in(&failures)
+        .into_iter()
+        .find(|path| path.to_str().unwrap().ends_with(".meta.json"))
+        .unwrap();
+    let metadata: serde_json::Value = serde_json::from_slice(&std::fs::read(failures.join(metadata)).unwrap()).unwrap();
+    let frames = metadata["backtrace"].as_array().unwrap();
+    assert!(!frames.is_empty());
+    // the frames of fuzzcheck itself, including the ones of its panic hook, are not part of the backtrace
+    for frame in frames {
+        assert!(frame.as_str().unwrap().contains("tests/fuzzer.rs"), "{}", frame);
+    }
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_resume_from_checkpoint() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("resume");
+    let stats = folder.join("stats");
+    let stats = stats.to_str().unwrap();
+    let test = |x: &[u8]| {
+        observe(x);
+        true
+    };
+    fuzz(
+        test,
+        &["--stats", stats, "--stop-after-iterations", "1000", "--seed", "2"],
+    );
+    let first_session = sessions(Path::new(stats)).pop().unwrap();
+    let (runs, arbitrary_inputs, inputs, pool_state) = read_checkpoint(&first_session);
+    assert!(runs >= 1000);
+    assert!(arbitrary_inputs >= 100);
+    assert!(!inputs.is_empty());
+    assert!(inputs.iter().any(|(_, mutations)| *mutations > 0));
+
+    // the second session reaches the maximum number of iterations as soon as it has resumed the first one
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let resume = first_session.to_str().unwrap();
+    let iterations = runs.to_string();
+    fuzz(
+        test,
+        &[
+            "--stats",
+            stats,
+            "--resume",
+            resume,
+            "--stop-after-iterations",
+            &iterations,
+        ],
+    );
+    let second_session = sessions(Path::new(stats)).pop().unwrap();
+    assert_ne!(first_session, second_session);
+    let (resumed_runs, resumed_arbitrary_inputs, resumed_inputs, resumed_pool_state) = read_checkpoint(&second_session);
+    assert_eq!(resumed_runs, runs);
+    // the arbitrary step continues from where it was, before generating new test cases to start the session,
+    // and so do the mutation steps, which are not used again since the session stops immediately
+    assert!(resumed_arbitrary_inputs >= arbitrary_inputs + 100);
+    assert!(inputs.iter().all(|input| resumed_inputs.contains(input)));
+    assert_eq!(resumed_pool_state, pool_state);
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_resume_from_corrupt_checkpoint() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("resume-corrupt");
+    let stats = folder.join("stats");
+    let stats = stats.to_str().unwrap();
+    let test = |x: &[u8]| {
+        observe(x);
+        true
+    };
+    fuzz(
+        test,
+        &["--stats", stats, "--stop-after-iterations", "100", "--seed", "2"],
+    );
+    let first_session = sessions(Path::new(stats)).pop().unwrap();
+    // the fuzzer was killed while writing the checkpoint
+    let checkpoint = std::fs::read(first_session.join("checkpoint.bin")).unwrap();
+    std::fs::write(
+        first_session.join("checkpoint.bin"),
+        &checkpoint[..checkpoint.len() / 2],
+    )
+    .unwrap();
+
+    // the session is resumed from scratch instead
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    let result = fuzz(
+        test,
+        &[
+            "--stats",
+            stats,
+            "--resume",
+            first_session.to_str().unwrap(),
+            "--stop-after-iterations",
+            "100",
+        ],
+    );
+    assert!(matches!(
+        result.reason_for_stopping,
+        ReasonForStopping::MaxIterationsReached
+    ));
+    let second_session = sessions(Path::new(stats)).pop().unwrap();
+    assert_ne!(first_session, second_session);
+    let (runs, _, inputs, _) = read_checkpoint(&second_session);
+    assert!((100..1000).contains(&runs));
+    assert!(!inputs.is_empty());
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_same_seed_same_corpus() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("seed");
+    let test = |x: &[u8]| {
+        observe(x);
+        true
+    };
+    let corpora = ["first", "second"].map(|name| {
+        let out_corpus = folder.join(name);
+        fuzz(
+            test,
+            &[
+                "--out-corpus",
+                out_corpus.to_str().unwrap(),
+                "--stop-after-iterations",
+                "2000",
+                "--seed",
+                "3",
+            ],
+        );
+        files_in(&out_corpus)
+            .into_iter()
+            .map(|path| {
+                let content = std::fs::read(out_corpus.join(&path)).unwrap();
+                (path, content)
+            })
+            .collect::<Vec<_>>()
+    });
+    assert!(!corpora[0].is_empty());
+    assert_eq!(corpora[0], corpora[1]);
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_fork_server_survives_crashes() {
+    let _guard = lock_fuzzer();
+    let result = fuzz(
+        |x: &[u8]| {
+            if x.len() >= 3 {
+                std::process::abort();
+            }
+            true
+        },
+        &[
+            "--fork",
+            "--stop-after-first-failure",
+            "--stop-after-iterations",
+            "2000",
+            "--seed",
+            "4",
+        ],
+    );
+    assert!(result.found_test_failure);
+    match result.reason_for_stopping {
+        ReasonForStopping::TestFailure(input) => assert!(input.len() >= 3),
+        _ => panic!("the fuzzer should have stopped on the crash"),
+    }
+}
+
+#[test]
+fn test_fork_server_runs_test_cases() {
+    let _guard = lock_fuzzer();
+    let result = fuzz(
+        |x: &[u8]| x.len() < 17,
+        &["--fork", "--stop-after-iterations", "500", "--seed", "5"],
+    );
+    // `found_test_failure` also records the failures found by the previous fuzz tests of this process
+    assert!(matches!(
+        result.reason_for_stopping,
+        ReasonForStopping::MaxIterationsReached
+    ));
+
+    let result = fuzz(
+        |x: &[u8]| {
+            assert!(x.len() < 3, "the test case is too long");
+            true
+        },
+        &[
+            "--fork",
+            "--stop-after-first-failure",
+            "--stop-after-iterations",
+            "2000",
+            "--seed",
+            "5",
+        ],
+    );
+    assert!(result.found_test_failure);
+    match result.reason_for_stopping {
+        ReasonForStopping::TestFailure(input) => assert!(input.len() >= 3),
+        _ => panic!("the fuzzer should have stopped on the test failure"),
+    }
+}
+
+#[cfg(feature = "serde_json_serializer")]
+mod model_based {
+    use fuzzcheck::reporters::QuietReporter;
+    use fuzzcheck::sensors_and_pools::{ArrayOfCounters, SimplestToActivateCounterPool};
+    use fuzzcheck::{DefaultMutator, ReasonForStopping, SensorExt, StateMachine};
+    use serde::{Deserialize, Serialize};
+
+    use super::{arguments, lock_fuzzer, test_folder, COUNTERS};
+
+    #[derive(Clone, Debug, PartialEq, DefaultMutator, Serialize, Deserialize)]
+    enum Op {
+        Push(u8),
+        Pop,
+        Len,
+    }
+
+    /// A stack that returns a value when it is popped while empty
+    struct BuggyStack(Vec<u8>);
+    /// The model of a stack
+    struct Stack(Vec<u8>);
+
+    impl StateMachine<Op> for BuggyStack {
+        type Output = Option<usize>;
+        fn apply(&mut self, operation: &Op) -> Option<usize> {
+            match operation {
+                Op::Push(x) => {
+                    self.0.push(*x);
+                    None
+                }
+                Op::Pop => Some(self.0.pop().unwrap_or(0) as usize),
+                Op::Len => Some(self.0.len()),
+            }
+        }
+    }
+
+    impl StateMachine<Op> for Stack {
+        type Output = Option<usize>;
+        fn apply(&mut self, operation: &Op) -> Option<usize> {
+            match operation {
+                Op::Push(x) => {
+                    self.0.push(*x);
+                    None
+                }
+                Op::Pop => self.0.pop().map(|x| x as usize),
+                Op::Len => Some(self.0.len()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_minify_model_based_failure() {
+        let _guard = lock_fuzzer();
+        let folder = test_folder("model-based");
+        let input_file = folder.join("input.json");
+        let operations = vec![
+            Op::Push(1),
+            Op::Len,
+            Op::Push(2),
+            Op::Pop,
+            Op::Pop,
+            Op::Push(3),
+            Op::Pop,
+            Op::Len,
+            Op::Pop,
+            Op::Push(4),
+            Op::Len,
+        ];
+        std::fs::write(&input_file, serde_json::to_vec(&operations).unwrap()).unwrap();
+        let artifacts = folder.join("artifacts");
+
+        let sensor = ArrayOfCounters::new(unsafe { &mut COUNTERS }).map(|o| {
+            o.iter()
+                .copied()
+                .enumerate()
+                .filter(|(_, c)| *c != 0)
+                .collect::<Vec<_>>()
+        });
+        let result = fuzzcheck::fuzz_test_model_based(|| BuggyStack(vec![]), || Stack(vec![]))
+            .default_mutator()
+            .serde_serializer()
+            .sensor_and_pool(sensor, SimplestToActivateCounterPool::new("simplest_to_activate", 4))
+            .arguments(arguments(&[
+                "--command",
+                "minify",
+                "--input-file",
+                input_file.to_str().unwrap(),
+                "--artifacts",
+                artifacts.to_str().unwrap(),
+                "--stop-after-iterations",
+                "10000",
+            ]))
+            .reporter(QuietReporter)
+            .launch_even_if_cfg_fuzzing_is_not_set();
+        match result.reason_for_stopping {
+            ReasonForStopping::TestFailure(operations) => assert_eq!(operations, vec![Op::Pop]),
+            reason => panic!(
+                "the minified test case should fail, but the fuzzer stopped: {:?}",
+                reason
+            ),
+        }
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+}
+
+/// A test function that hangs on test cases of at least 3 bytes
+fn hang_on_long_test_cases(x: &[u8]) -> bool {
+    observe(x);
+    if x.len() >= 3 {
+        std::thread::sleep(std::time::Duration::from_secs(10));
+    }
+    true
+}
+
+#[test]
+fn test_timeouts_are_test_failures() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("timeout");
+    let artifacts = folder.join("artifacts");
+    let out_corpus = folder.join("out_corpus");
+    let result = fuzz(
+        hang_on_long_test_cases,
+        &[
+            "--fork",
+            "--timeout-ms",
+            "50",
+            "--artifacts",
+            artifacts.to_str().unwrap(),
+            "--out-corpus",
+            out_corpus.to_str().unwrap(),
+            "--stop-after-iterations",
+            "200",
+            "--seed",
+            "1",
+        ],
+    );
+    assert!(matches!(
+        result.reason_for_stopping,
+        ReasonForStopping::MaxIterationsReached
+    ));
+    // the timeouts are not all saved as artifacts, but are grouped into a single kind of test failure
+    assert!(!artifacts.join("timeout").exists());
+    let failures = files_in(&out_corpus.join("test_failures"));
+    assert!(!failures.is_empty());
+    let failure_ids = failures
+        .iter()
+        .map(|path| path.iter().next().unwrap().to_owned())
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(failure_ids.len(), 1);
+
+    // and the fuzzer stops at the first one if asked to
+    let result = fuzz(
+        hang_on_long_test_cases,
+        &[
+            "--fork",
+            "--timeout-ms",
+            "50",
+            "--artifacts",
+            artifacts.to_str().unwrap(),
+            "--stop-after-first-failure",
+            "--stop-after-iterations",
+            "200",
+            "--seed",
+            "1",
+        ],
+    );
+    let ReasonForStopping::TestFailure(value) = result.reason_for_stopping else {
+        panic!("the fuzzer should have stopped at the first timeout");
+    };
+    let timeouts = files_in(&artifacts.join("timeout"))
+        .into_iter()
+        .filter(|path| path.extension().unwrap() == "bin")
+        .map(|path| std::fs::read(artifacts.join("timeout").join(path)).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(timeouts, [value]);
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_replay_reports_timeouts() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("replay-timeout");
+    let corpus = folder.join("corpus");
+    std::fs::create_dir_all(&corpus).unwrap();
+    std::fs::write(corpus.join("a.bin"), [1]).unwrap();
+    std::fs::write(corpus.join("b.bin"), [1, 2, 3]).unwrap();
+    let result = fuzz(
+        hang_on_long_test_cases,
+        &[
+            "--command",
+            "replay",
+            "--in-corpus",
+            corpus.to_str().unwrap(),
+            "--timeout-ms",
+            "50",
+        ],
+    );
+    let ReasonForStopping::Replayed(mut report) = result.reason_for_stopping else {
+        panic!("the fuzzer should have replayed the corpus");
+    };
+    report.results.sort_by(|a, b| a.0.cmp(&b.0));
+    let outcomes = report
+        .results
+        .into_iter()
+        .map(|(_, outcome)| outcome)
+        .collect::<Vec<_>>();
+    assert_eq!(outcomes, [ReplayOutcome::Passed, ReplayOutcome::TimedOut]);
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_replay_keeps_going_after_a_crash() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("replay");
+    let corpus = folder.join("corpus");
+    std::fs::create_dir_all(&corpus).unwrap();
+    for (name, content) in [
+        ("a.bin", &[1u8][..]),
+        ("b.bin", &[0, 0, 0]),
+        ("c.bin", &[2, 3]),
+        ("d.bin", &[4, 4]),
+    ] {
+        std::fs::write(corpus.join(name), content).unwrap();
+    }
+    let result = fuzz(
+        |x: &[u8]| {
+            if x.len() >= 3 {
+                std::process::abort();
+            }
+            x != [4, 4]
+        },
+        &["--command", "replay", "--in-corpus", corpus.to_str().unwrap()],
+    );
+    let report = match result.reason_for_stopping {
+        ReasonForStopping::Replayed(report) => report,
+        reason => panic!(
+            "the fuzzer should have replayed the corpus, but it stopped: {:?}",
+            reason
+        ),
+    };
+    let mut outcomes = report
+        .results
+        .into_iter()
+        .map(|(path, outcome)| (path.file_name().unwrap().to_str().unwrap().to_owned(), outcome))
+        .collect::<Vec<_>>();
+    outcomes.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(outcomes.len(), 4);
+    assert_eq!(outcomes[0].1, ReplayOutcome::Passed);
+    assert!(matches!(&outcomes[1].1, ReplayOutcome::Failed(message) if message.contains("crashed")));
+    assert_eq!(outcomes[2].1, ReplayOutcome::Passed);
+    assert!(matches!(outcomes[3].1, ReplayOutcome::Failed(_)));
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_minify_corpus_keeps_the_simplest_test_cases() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("cmin");
+    let in_corpus = folder.join("in_corpus");
+    let out_corpus = folder.join("out_corpus");
+    std::fs::create_dir_all(&in_corpus).unwrap();
+    for (name, content) in [
+        ("a.bin", &[1u8][..]),
+        ("b.bin", &[1, 1]),
+        ("c.bin", &[0]),
+        ("d.bin", &[1, 2]),
+        ("e.bin", &[1, 2, 1]),
+    ] {
+        std::fs::write(in_corpus.join(name), content).unwrap();
+    }
+    let result = fuzz(
+        |x: &[u8]| {
+            observe(x);
+            true
+        },
+        &[
+            "--command",
+            "cmin",
+            "--in-corpus",
+            in_corpus.to_str().unwrap(),
+            "--out-corpus",
+            out_corpus.to_str().unwrap(),
+        ],
+    );
+    assert!(matches!(result.reason_for_stopping, ReasonForStopping::MinifiedCorpus));
+    let mut contents = files_in(&out_corpus)
+        .into_iter()
+        .filter(|path| path.extension().map_or(false, |e| e == "bin"))
+        .map(|path| std::fs::read(out_corpus.join(path)).unwrap())
+        .collect::<Vec<_>>();
+    contents.sort();
+    // [1, 1] and [1, 2, 1] do not activate any counter in a simpler way than the other test cases
+    assert_eq!(contents, vec![vec![0], vec![1], vec![1, 2]]);
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+async fn async_test(x: &[u8]) -> Result<(), String> {
+    observe(x);
+    std::future::ready(()).await;
+    if x.len() >= 3 {
+        Err(format!("the test case is too long: {:?}", x))
+    } else {
+        Ok(())
+    }
+}
+
+async fn stuck_async_test(x: &[u8]) {
+    observe(x);
+    if x.len() >= 3 {
+        std::future::pending::<()>().await;
+    }
+}
+
+#[test]
+fn test_async_test_functions() {
+    let _guard = lock_fuzzer();
+    let args = [
+        "--stop-after-first-failure",
+        "--stop-after-iterations",
+        "2000",
+        "--seed",
+        "1",
+    ];
+    for result in [fuzz(async_test, &args), fuzz(stuck_async_test, &args)] {
+        let ReasonForStopping::TestFailure(value) = result.reason_for_stopping else {
+            panic!("the fuzzer should have found a test failure");
+        };
+        assert!(value.len() >= 3);
+    }
+}
+
+#[test]
+fn test_jobs_find_test_failures() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("jobs");
+    let artifacts = folder.join("artifacts");
+    let result = fuzz(
+        |x: &[u8]| {
+            observe(x);
+            x.len() < 3
+        },
+        &[
+            "--jobs",
+            "3",
+            "--stop-after-first-failure",
+            "--artifacts",
+            artifacts.to_str().unwrap(),
+            "--stop-after-iterations",
+            "2000",
+            "--seed",
+            "1",
+        ],
+    );
+    let ReasonForStopping::TestFailure(value) = result.reason_for_stopping else {
+        panic!("the fuzzer should have found a test failure");
+    };
+    assert!(value.len() >= 3);
+    // the artifact is the test case that failed, not one tested by another fork server
+    let artifacts = files_in(&artifacts)
+        .into_iter()
+        .filter(|path| path.extension().unwrap() == "bin")
+        .map(|path| std::fs::read(artifacts.join(path)).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(artifacts, [value]);
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_invalid_corpus_fail_stops_the_fuzzer() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("invalid-corpus");
+    let in_corpus = folder.join("in_corpus");
+    std::fs::create_dir_all(&in_corpus).unwrap();
+    std::fs::write(in_corpus.join("a.bin"), [1, 2]).unwrap();
+    // a file that cannot be read
+    std::os::unix::fs::symlink(folder.join("missing.bin"), in_corpus.join("b.bin")).unwrap();
+    let result = fuzz(
+        |x: &[u8]| {
+            observe(x);
+            true
+        },
+        &[
+            "--in-corpus",
+            in_corpus.to_str().unwrap(),
+            "--invalid-corpus",
+            "fail",
+            "--stop-after-iterations",
+            "100",
+        ],
+    );
+    assert!(matches!(result.reason_for_stopping, ReasonForStopping::InvalidCorpus));
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+#[test]
+fn test_minify_keeps_the_same_test_failure() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("minify-same-failure");
+    let input_file = folder.join("input.bin");
+    std::fs::write(&input_file, [3, 9, 2, 7, 5]).unwrap();
+    let artifacts = folder.join("artifacts");
+    let result = fuzz(
+        |x: &[u8]| {
+            assert!(!x.contains(&0), "the test case contains a zero");
+            assert!(x.len() < 5, "the test case is too long");
+            true
+        },
+        &[
+            "--command",
+            "minify",
+            "--input-file",
+            input_file.to_str().unwrap(),
+            "--artifacts",
+            artifacts.to_str().unwrap(),
+            "--stop-after-iterations",
+            "1000",
+        ],
+    );
+    // replacing an element by zero makes the test fail, but for a different reason, so it is not a simplification
+    match result.reason_for_stopping {
+        ReasonForStopping::TestFailure(input) => assert_eq!(input, [1, 1, 1, 1, 1]),
+        reason => panic!(
+            "the minified test case should fail, but the fuzzer stopped: {:?}",
+            reason
+        ),
+    }
+    let _ = std::fs::remove_dir_all(&folder);
+}
+
+/// The values passed to `crossover_mutate`, along with the value they were combined with
+static CROSSOVERS: Mutex<Vec<(Vec<u8>, Vec<u8>)>> = Mutex::new(vec![]);
+
+/// A mutator of pairs of bytes whose mutations keep both bytes below 0x80, so that a pair of larger bytes can
+/// only be obtained by taking its two bytes from two different inputs
+struct PairMutator;
+
+impl Mutator<Vec<u8>> for PairMutator {
+    type Cache = ();
+    type MutationStep = usize;
+    type ArbitraryStep = u8;
+    /// The index of the mutated byte and its previous value
+    type UnmutateToken = (usize, u8);
+    type RecursingPartIndex = ();
+
+    fn default_arbitrary_step(&self) -> Self::ArbitraryStep {
+        0
+    }
+    fn validate_value(&self, value: &Vec<u8>) -> Option<Self::Cache> {
+        (value.len() == 2).then_some(())
+    }
+    fn default_mutation_step(&self, _value: &Vec<u8>, _cache: &Self::Cache) -> Self::MutationStep {
+        0
+    }
+    fn max_complexity(&self) -> f64 {
+        16.0
+    }
+    fn min_complexity(&self) -> f64 {
+        16.0
+    }
+    fn complexity(&self, _value: &Vec<u8>, _cache: &Self::Cache) -> f64 {
+        16.0
+    }
+    fn ordered_arbitrary(&self, step: &mut Self::ArbitraryStep, _max_cplx: f64) -> Option<(Vec<u8>, f64)> {
+        *step = (*step + 1) & 0x7F;
+        Some((vec![*step, 0], 16.0))
+    }
+    fn random_arbitrary(&self, _max_cplx: f64) -> (Vec<u8>, f64) {
+        (vec![0, 0], 16.0)
+    }
+    fn ordered_mutate(
+        &self,
+        value: &mut Vec<u8>,
+        _cache: &mut Self::Cache,
+        step: &mut Self::MutationStep,
+        _max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        *step += 1;
+        Some((increment_below_0x80(value, *step % 2), 16.0))
+    }
+    fn random_mutate(
+        &self,
+        value: &mut Vec<u8>,
+        _cache: &mut Self::Cache,
+        _max_cplx: f64,
+    ) -> (Self::UnmutateToken, f64) {
+        (increment_below_0x80(value, 0), 16.0)
+    }
+    fn unmutate(&self, value: &mut Vec<u8>, _cache: &mut Self::Cache, (idx, byte): Self::UnmutateToken) {
+        value[idx] = byte;
+    }
+    fn default_recursing_part_index(&self, _value: &Vec<u8>, _cache: &Self::Cache) -> Self::RecursingPartIndex {}
+    fn recursing_part<'a, T, M>(
+        &self,
+        _parent: &M,
+        _value: &'a Vec<u8>,
+        _index: &mut Self::RecursingPartIndex,
+    ) -> Option<&'a T>
+    where
+        T: Clone + 'static,
+        M: Mutator<T>,
+    {
+        None
+    }
+    /// Replace the second byte of the value by the second byte of `other`
+    fn crossover_mutate(
+        &self,
+        value: &mut Vec<u8>,
+        _cache: &mut Self::Cache,
+        other: &Vec<u8>,
+        _other_cache: &Self::Cache,
+        _max_cplx: f64,
+    ) -> Option<(Self::UnmutateToken, f64)> {
+        CROSSOVERS.lock().unwrap().push((value.clone(), other.clone()));
+        let token = (1, value[1]);
+        value[1] = other[1];
+        Some((token, 16.0))
+    }
+}
+
+/// Increment the byte at the given index, wrapping around at 0x80, and return the token to unmutate it
+fn increment_below_0x80(value: &mut [u8], idx: usize) -> (usize, u8) {
+    let token = (idx, value[idx]);
+    value[idx] = value[idx].wrapping_add(1) & 0x7F;
+    token
+}
+
+#[test]
+fn test_crossover_combines_two_inputs_of_the_pool() {
+    let _guard = lock_fuzzer();
+    let folder = test_folder("crossover");
+    let in_corpus = folder.join("in_corpus");
+    let out_corpus = folder.join("out_corpus");
+    std::fs::create_dir_all(&in_corpus).unwrap();
+    std::fs::write(in_corpus.join("first.bin"), [0xAA, 0]).unwrap();
+    std::fs::write(in_corpus.join("second.bin"), [0, 0xBB]).unwrap();
+    CROSSOVERS.lock().unwrap().clear();
+
+    let result = fuzz_with_mutator(
+        || PairMutator,
+        |x: &[u8]| {
+            unsafe {
+                COUNTERS = [(x[0] == 0xAA) as u64, (x[1] == 0xBB) as u64, 0, 0];
+                COUNTERS[2] = COUNTERS[0] * COUNTERS[1];
+            }
+            true
+        },
+        &[
+            "--in-corpus",
+            in_corpus.to_str().unwrap(),
+            "--out-corpus",
+            out_corpus.to_str().unwrap(),
+            "--stop-after-iterations",
+            "1000",
+            "--seed",
+            "3",
+        ],
+    );
+    assert!(matches!(
+        result.reason_for_stopping,
+        ReasonForStopping::MaxIterationsReached
+    ));
+
+    // the fuzzer combined an input of the pool with another one
+    let crossovers = CROSSOVERS.lock().unwrap().clone();
+    assert!(crossovers.contains(&(vec![0xAA, 0], vec![0, 0xBB])), "{:?}", crossovers);
+    assert!(crossovers.iter().all(|(value, other)| value != other));
+
+    // the combination activated a new counter, so it was added to the pool
+    let contents = files_in(&out_corpus)
+        .into_iter()
+        .filter(|path| path.extension().map_or(false, |e| e == "bin"))
+        .map(|path| std::fs::read(out_corpus.join(path)).unwrap())
+        .collect::<Vec<_>>();
+    assert!(contents.contains(&vec![0xAA, 0xBB]), "{:?}", contents);
+    let _ = std::fs::remove_dir_all(&folder);
+}
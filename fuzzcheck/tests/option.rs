@@ -5,3 +5,12 @@ fn test_option() {
     let m = OptionMutator::new(U8Mutator::default());
     fuzzcheck::mutators::testing_utilities::test_mutator(m, 100.0, 100.0, false, true, 500, 500);
 }
+
+#[test]
+fn test_option_simplify() {
+    let m = OptionMutator::new(U8Mutator::default());
+    let simplest = fuzzcheck::mutators::testing_utilities::simplify(&m, Some(200), |_| true);
+    assert_eq!(simplest, None);
+    let simplest = fuzzcheck::mutators::testing_utilities::simplify(&m, Some(200), |x| x.is_some());
+    assert_eq!(simplest, Some(0));
+}
@@ -1,4 +1,5 @@
 use fuzzcheck::mutators::{integer::U8Mutator, vector::VecMutator};
+use fuzzcheck::Mutator;
 #[test]
 fn test_vector_mutator() {
     // let m = VecMutator::new(U8Mutator::default(), 0..=10);
@@ -83,3 +84,107 @@ fn test_vector_mutator() {
 //         }
 //     }
 // }
+
+#[test]
+fn test_vector_simplify() {
+    let m = VecMutator::new(U8Mutator::default(), 0..=usize::MAX);
+    let value = vec![3, 200, 7, 15, 0, 99, 12, 1];
+    // the simplest vector that contains an element greater than 10
+    let simplest = fuzzcheck::mutators::testing_utilities::simplify(&m, value, |x| x.iter().any(|&el| el > 10));
+    assert_eq!(simplest, vec![11]);
+
+    let m = VecMutator::new(U8Mutator::default(), 3..=usize::MAX);
+    let value = vec![3, 200, 7, 15, 0, 99, 12, 1];
+    let simplest = fuzzcheck::mutators::testing_utilities::simplify(&m, value, |_| true);
+    assert_eq!(simplest, vec![0, 0, 0]);
+}
+
+#[test]
+fn test_vector_crossover() {
+    let m = VecMutator::new(VecMutator::new(U8Mutator::default(), 0..=usize::MAX), 0..=usize::MAX);
+    let nbr_successes = fuzzcheck::mutators::testing_utilities::test_crossover(&m, 500.0, 1000);
+    assert!(nbr_successes > 0);
+
+    let m = VecMutator::new(U8Mutator::default(), 2..=4);
+    let nbr_successes = fuzzcheck::mutators::testing_utilities::test_crossover(&m, 100.0, 1000);
+    assert!(nbr_successes > 0);
+}
+
+/// Check that the description of a mutation of a vector matches the difference between the value
+/// before and after the mutation
+fn check_vector_mutation_description(before: &[u8], after: &[u8], description: &str) {
+    let numbers = description
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().unwrap())
+        .collect::<Vec<_>>();
+    if description.starts_with("element ") {
+        let idx = numbers[0];
+        if description.ends_with("unchanged") {
+            assert_eq!(before, after);
+        } else {
+            assert_eq!(
+                description,
+                format!("element {}: {} -> {}", idx, before[idx], after[idx])
+            );
+            assert_eq!(before[..idx], after[..idx]);
+            assert_eq!(before[idx + 1..], after[idx + 1..]);
+        }
+    } else if description.starts_with("remove the element at index ") && description.contains(" and insert ") {
+        let mut before = before.to_vec();
+        let mut after = after.to_vec();
+        before.remove(numbers[0]);
+        after.remove(numbers[1]);
+        assert_eq!(before, after);
+    } else if description.starts_with("remove the element at index ") {
+        let mut before = before.to_vec();
+        before.remove(numbers[0]);
+        assert_eq!(before, after);
+    } else if description.starts_with("insert an element at index ") {
+        let mut after = after.to_vec();
+        after.remove(numbers[0]);
+        assert_eq!(before, after);
+    } else if description.starts_with("insert ") {
+        let (len, idx) = (numbers[0], numbers[1]);
+        let mut after = after.to_vec();
+        after.drain(idx..idx + len);
+        assert_eq!(before, after);
+    } else if description.starts_with("swap elements ") {
+        let mut before = before.to_vec();
+        before.swap(numbers[0], numbers[1]);
+        assert_eq!(before, after);
+    } else if description.starts_with("replace with an arbitrary vector of length ") {
+        assert_eq!(after.len(), numbers[0]);
+    } else if description.starts_with("replace with a vector of ") {
+        assert_eq!(after.len(), numbers[0]);
+        assert!(after.windows(2).all(|w| w[0] == w[1]));
+    } else {
+        panic!("unexpected description of a vector mutation: {}", description);
+    }
+}
+
+#[test]
+fn test_vector_describe_mutation() {
+    let m = U8Mutator::default();
+    let mut value = 3;
+    let mut cache = m.validate_value(&value).unwrap();
+    let (token, _) = m.random_mutate(&mut value, &mut cache, 100.0);
+    assert_eq!(m.describe_mutation(&value, &token), format!("3 -> {}", value));
+
+    let m = VecMutator::new(U8Mutator::default(), 0..=usize::MAX);
+    let original = vec![3, 200, 7, 15];
+    let mut value = original.clone();
+    let mut cache = m.validate_value(&value).unwrap();
+    let mut step = m.default_mutation_step(&value, &cache);
+    for _ in 0..100 {
+        let (token, _) = m.random_mutate(&mut value, &mut cache, 100.0);
+        check_vector_mutation_description(&original, &value, &m.describe_mutation(&value, &token));
+        m.unmutate(&mut value, &mut cache, token);
+        assert_eq!(value, original);
+        if let Some((token, _)) = m.ordered_mutate(&mut value, &mut cache, &mut step, 100.0) {
+            check_vector_mutation_description(&original, &value, &m.describe_mutation(&value, &token));
+            m.unmutate(&mut value, &mut cache, token);
+            assert_eq!(value, original);
+        }
+    }
+}
@@ -20,20 +20,45 @@ pub const COMMAND_FLAG: &str = "command";
 
 pub const MAX_DURATION_FLAG: &str = "stop-after-duration";
 pub const MAX_ITERATIONS_FLAG: &str = "stop-after-iterations";
+pub const NO_PROGRESS_FLAG: &str = "stop-after-no-progress";
+pub const STOP_AT_COVERAGE_FLAG: &str = "stop-at-coverage";
 pub const STOP_AFTER_FIRST_FAILURE_FLAG: &str = "stop-after-first-failure";
+pub const TIMEOUT_FLAG: &str = "timeout-ms";
+pub const FORK_FLAG: &str = "fork";
+pub const TRACE_COMPARES_FLAG: &str = "trace-compares";
+pub const JOBS_FLAG: &str = "jobs";
+pub const CORPUS_SYNC_INTERVAL_FLAG: &str = "corpus-sync-interval";
+pub const RESUME_FLAG: &str = "resume";
+pub const SEED_FLAG: &str = "seed";
+pub const INVALID_CORPUS_FLAG: &str = "invalid-corpus";
+pub const BACKTRACE_FRAMES_FLAG: &str = "backtrace-frames";
+pub const EVENTS_FLAG: &str = "events";
+pub const EVENTS_FD_FLAG: &str = "events-fd";
 
 pub const COMMAND_FUZZ: &str = "fuzz";
 pub const COMMAND_MINIFY_INPUT: &str = "minify";
+pub const COMMAND_MINIFY_CORPUS: &str = "cmin";
+pub const COMMAND_MERGE: &str = "merge";
+pub const COMMAND_REPLAY: &str = "replay";
 pub const COMMAND_READ: &str = "read";
 
+pub const INVALID_CORPUS_IGNORE: &str = "ignore";
+pub const INVALID_CORPUS_WARN: &str = "warn";
+pub const INVALID_CORPUS_QUARANTINE: &str = "quarantine";
+pub const INVALID_CORPUS_FAIL: &str = "fail";
+
 #[derive(Clone)]
 pub struct DefaultArguments {
     pub max_input_cplx: f64,
+    pub corpus_sync_interval: Duration,
 }
 impl Default for DefaultArguments {
     #[no_coverage]
     fn default() -> Self {
-        Self { max_input_cplx: 4096.0 }
+        Self {
+            max_input_cplx: 4096.0,
+            corpus_sync_interval: Duration::from_secs(30),
+        }
     }
 }
 
@@ -43,6 +68,9 @@ pub enum FuzzerCommand {
     Fuzz,
     Read { input_file: PathBuf },
     MinifyInput { input_file: PathBuf },
+    MinifyCorpus,
+    MergeCorpora { corpora: Vec<PathBuf> },
+    Replay { folders: Vec<PathBuf> },
 }
 impl Default for FuzzerCommand {
     fn default() -> Self {
@@ -50,6 +78,139 @@ impl Default for FuzzerCommand {
     }
 }
 
+/// What the fuzzer does with the files of a corpus that cannot be read or decoded into valid test cases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidCorpusPolicy {
+    /// Skip them silently
+    Ignore,
+    /// Skip them and list them, along with the reason why they were rejected
+    Warn,
+    /// Move them to the `rejected` subfolder of their corpus and list them
+    Quarantine,
+    /// List them and stop the fuzzer
+    Fail,
+}
+impl Default for InvalidCorpusPolicy {
+    #[no_coverage]
+    fn default() -> Self {
+        Self::Warn
+    }
+}
+impl InvalidCorpusPolicy {
+    /// The value of the command line option corresponding to the policy
+    #[no_coverage]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ignore => INVALID_CORPUS_IGNORE,
+            Self::Warn => INVALID_CORPUS_WARN,
+            Self::Quarantine => INVALID_CORPUS_QUARANTINE,
+            Self::Fail => INVALID_CORPUS_FAIL,
+        }
+    }
+}
+
+/// How long the fuzzer keeps running after the last time it added a test case to its corpus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoProgressLimit {
+    /// Stop after the given duration without progress
+    Duration(Duration),
+    /// Stop after the given number of iterations without progress
+    Iterations(usize),
+}
+impl NoProgressLimit {
+    /// Parse the value of the command line option: a number of iterations, or a duration
+    /// in seconds, minutes, or hours, such as `30s`, `5m`, or `1h`.
+    #[no_coverage]
+    pub fn parse(s: &str) -> Option<Self> {
+        let (number, seconds_per_unit) = match s.as_bytes().last()? {
+            b's' => (&s[..s.len() - 1], Some(1)),
+            b'm' => (&s[..s.len() - 1], Some(60)),
+            b'h' => (&s[..s.len() - 1], Some(60 * 60)),
+            _ => (s, None),
+        };
+        let number = number.parse::<u64>().ok()?;
+        if let Some(seconds_per_unit) = seconds_per_unit {
+            Some(Self::Duration(Duration::from_secs(
+                number.checked_mul(seconds_per_unit)?,
+            )))
+        } else {
+            Some(Self::Iterations(number as usize))
+        }
+    }
+}
+impl Display for NoProgressLimit {
+    #[no_coverage]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Duration(duration) => write!(f, "{}s", duration.as_secs()),
+            Self::Iterations(iterations) => write!(f, "{}", iterations),
+        }
+    }
+}
+
+/// The code coverage at which the fuzzer stops.
+///
+/// It is measured on the files whose paths are relative to the current directory, like the default code
+/// coverage sensor, whatever the sensor used by the fuzzer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoverageTarget {
+    /// A percentage of the code regions
+    Percentage(f64),
+    /// Any code region of the functions whose name, without its hash, is either the given name or ends with `::<name>`
+    Function(String),
+    /// Any of the innermost code regions containing the given line of the given file
+    Line { file: PathBuf, line: usize },
+}
+impl CoverageTarget {
+    /// Parse the value of the command line option: a percentage such as `80%`, a source line
+    /// such as `src/parser.rs:42`, or otherwise the name of a function.
+    #[no_coverage]
+    pub fn parse(s: &str) -> Option<Self> {
+        if s.is_empty() {
+            return None;
+        }
+        if let Some(percentage) = s.strip_suffix('%') {
+            let percentage = percentage.parse::<f64>().ok()?;
+            return if (0.0..=100.0).contains(&percentage) {
+                Some(Self::Percentage(percentage))
+            } else {
+                None
+            };
+        }
+        if let Some((file, line)) = s.rsplit_once(':') {
+            if let Ok(line) = line.parse::<usize>() {
+                if file.is_empty() || file.ends_with(':') {
+                    return None;
+                }
+                return Some(Self::Line {
+                    file: PathBuf::from(file),
+                    line,
+                });
+            }
+        }
+        Some(Self::Function(s.to_owned()))
+    }
+}
+impl Display for CoverageTarget {
+    #[no_coverage]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Percentage(percentage) => write!(f, "{}%", percentage),
+            Self::Function(name) => write!(f, "{}", name),
+            Self::Line { file, line } => write!(f, "{}:{}", file.display(), line),
+        }
+    }
+}
+
+/// Where the fuzzer writes its events, as one JSON object per line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventStream {
+    /// Append the events to the file at the given path
+    File(PathBuf),
+    /// Write the events to the given file descriptor, which must be open for writing
+    FileDescriptor(i32),
+}
+
 /// Various arguments given to the fuzzer, typically provided by the `cargo fuzzcheck` command line tool.
 #[derive(Debug, Clone)]
 pub struct Arguments {
@@ -57,11 +218,23 @@ pub struct Arguments {
     pub max_input_cplx: f64,
     pub maximum_duration: Duration,
     pub maximum_iterations: usize,
+    pub stop_after_no_progress: Option<NoProgressLimit>,
+    pub stop_at_coverage: Option<CoverageTarget>,
+    pub timeout: Option<Duration>,
     pub stop_after_first_failure: bool,
+    pub fork: bool,
+    pub jobs: usize,
+    pub trace_compares: bool,
+    pub seed: Option<u64>,
+    pub backtrace_frames: Option<usize>,
+    pub corpus_sync_interval: Option<Duration>,
     pub corpus_in: Option<PathBuf>,
     pub corpus_out: Option<PathBuf>,
     pub artifacts_folder: Option<PathBuf>,
     pub stats_folder: Option<PathBuf>,
+    pub resume: Option<PathBuf>,
+    pub invalid_corpus: InvalidCorpusPolicy,
+    pub events: Option<EventStream>,
 }
 
 /// The command line argument parser used by the fuzz target and `cargo fuzzcheck`
@@ -78,7 +251,10 @@ pub fn options_parser() -> Options {
             "the action to be performed (default: fuzz). --{} is required when using `{}`",
             INPUT_FILE_FLAG, COMMAND_MINIFY_INPUT
         ),
-        &format!("<{} | {}>", COMMAND_FUZZ, COMMAND_MINIFY_INPUT),
+        &format!(
+            "<{} | {} | {} | {} | {}>",
+            COMMAND_FUZZ, COMMAND_MINIFY_INPUT, COMMAND_MINIFY_CORPUS, COMMAND_MERGE, COMMAND_REPLAY
+        ),
     );
     options.optopt(
         "",
@@ -87,13 +263,70 @@ pub fn options_parser() -> Options {
         "N",
     );
     options.optopt("", MAX_ITERATIONS_FLAG, "maximum number of iterations", "N");
+    options.optopt(
+        "",
+        NO_PROGRESS_FLAG,
+        "stop the fuzz test once no test case was added to the corpus for the given number of iterations, or for the given duration when followed by s, m, or h",
+        "<N | Ns | Nm | Nh>",
+    );
+    options.optopt(
+        "",
+        STOP_AT_COVERAGE_FLAG,
+        "stop the fuzz test once the given percentage of the code regions of the files relative to the current directory is covered, or once the given source line or function is reached",
+        "<N% | FILE:LINE | FUNCTION>",
+    );
+    options.optopt(
+        "",
+        TIMEOUT_FLAG,
+        "maximum duration of a single execution of the test function, in milliseconds. Test cases that take longer are test failures, saved as timeout artifacts",
+        "N",
+    );
     options.optflag(
         "",
         STOP_AFTER_FIRST_FAILURE_FLAG,
         "stop the fuzzer after the first test failure is found",
     );
+    options.optflag(
+        "",
+        FORK_FLAG,
+        "run the test function in a child process, such that the fuzzer survives crashes of the tested code",
+    );
+    options.optopt(
+        "",
+        JOBS_FLAG,
+        "number of child processes running the test function in parallel (default: 1). Implies --fork when larger than 1",
+        "N",
+    );
+    options.optflag(
+        "",
+        TRACE_COMPARES_FLAG,
+        "record the operands of the integer comparisons made by the test function, and use them to guide the fuzzer. Has no effect with --fork",
+    );
+    options.optopt(
+        "",
+        SEED_FLAG,
+        "seed of the random number generators of the mutator and pools, such that the fuzzer run can be reproduced",
+        "N",
+    );
 
-    options.optopt("", IN_CORPUS_FLAG, "folder for the input corpus", "PATH");
+    options.optopt(
+        "",
+        BACKTRACE_FRAMES_FLAG,
+        "identify test failures by the N innermost frames of their backtrace that belong to the tested crate, instead of by the location of the panic",
+        "N",
+    );
+
+    options.optmulti(
+        "",
+        IN_CORPUS_FLAG,
+        format!(
+            "folder for the input corpus, can be given several times with `{merge}` and `{replay}`",
+            merge = COMMAND_MERGE,
+            replay = COMMAND_REPLAY
+        )
+        .as_str(),
+        "PATH",
+    );
     options.optflag(
         "",
         NO_IN_CORPUS_FLAG,
@@ -104,6 +337,15 @@ pub fn options_parser() -> Options {
         .as_str(),
     );
     options.optopt("", OUT_CORPUS_FLAG, "folder for the output corpus", "PATH");
+    options.optopt(
+        "",
+        CORPUS_SYNC_INTERVAL_FLAG,
+        &format!(
+            "interval, in seconds, between two reads of the test cases that other fuzzers added to the output corpus (default: {}). 0 disables it",
+            defaults.corpus_sync_interval.as_secs()
+        ),
+        "N",
+    );
     options.optflag(
         "",
         NO_OUT_CORPUS_FLAG,
@@ -129,6 +371,39 @@ pub fn options_parser() -> Options {
         NO_STATS_FLAG,
         format!("do not save statistics, overrides --{stats}", stats = STATS_FLAG).as_str(),
     );
+    options.optopt(
+        "",
+        RESUME_FLAG,
+        "resume the fuzzing session whose statistics were written to the given folder",
+        "PATH",
+    );
+    options.optopt(
+        "",
+        INVALID_CORPUS_FLAG,
+        &format!(
+            "what to do with the corpus files that cannot be read or decoded into valid test cases (default: {})",
+            InvalidCorpusPolicy::default().as_str()
+        ),
+        &format!(
+            "<{} | {} | {} | {}>",
+            INVALID_CORPUS_IGNORE, INVALID_CORPUS_WARN, INVALID_CORPUS_QUARANTINE, INVALID_CORPUS_FAIL
+        ),
+    );
+    options.optopt(
+        "",
+        EVENTS_FLAG,
+        "file where each event of the fuzzer is appended as a JSON object on its own line",
+        "PATH",
+    );
+    options.optopt(
+        "",
+        EVENTS_FD_FLAG,
+        &format!(
+            "file descriptor where the events of the fuzzer are written, like --{}",
+            EVENTS_FLAG
+        ),
+        "FD",
+    );
     options.optopt("", INPUT_FILE_FLAG, "file containing a test case", "PATH");
     options.optopt(
         "",
@@ -172,12 +447,26 @@ impl Arguments {
 
         let command = command.as_str();
 
-        if !matches!(command, COMMAND_FUZZ | COMMAND_READ | COMMAND_MINIFY_INPUT) {
+        if !matches!(
+            command,
+            COMMAND_FUZZ | COMMAND_READ | COMMAND_MINIFY_INPUT | COMMAND_MINIFY_CORPUS | COMMAND_MERGE | COMMAND_REPLAY
+        ) {
             return Err(ArgumentsError::Validation(format!(
-                r#"The command {c} is not supported. It can either be ‘{fuzz}’ or ‘{minify}’."#,
+                r#"The command {c} is not supported. It can either be ‘{fuzz}’, ‘{minify}’, ‘{cmin}’, ‘{merge}’, or ‘{replay}’."#,
                 c = &matches.free[0],
                 fuzz = COMMAND_FUZZ,
                 minify = COMMAND_MINIFY_INPUT,
+                cmin = COMMAND_MINIFY_CORPUS,
+                merge = COMMAND_MERGE,
+                replay = COMMAND_REPLAY,
+            )));
+        }
+        if !matches!(command, COMMAND_MERGE | COMMAND_REPLAY) && matches.opt_count(IN_CORPUS_FLAG) > 1 {
+            return Err(ArgumentsError::Validation(format!(
+                "--{in_corpus} can only be given several times with the ‘{merge}’ and ‘{replay}’ commands.",
+                in_corpus = IN_CORPUS_FLAG,
+                merge = COMMAND_MERGE,
+                replay = COMMAND_REPLAY,
             )));
         }
 
@@ -236,6 +525,27 @@ impl Arguments {
             None
         };
 
+        let resume: Option<PathBuf> = matches.opt_str(RESUME_FLAG).and_then(
+            #[no_coverage]
+            |x| x.parse::<PathBuf>().ok(),
+        );
+
+        let events_fd: Option<i32> = matches.opt_str(EVENTS_FD_FLAG).and_then(
+            #[no_coverage]
+            |x| x.parse::<i32>().ok(),
+        );
+        let events = match (matches.opt_str(EVENTS_FLAG), events_fd) {
+            (Some(_), Some(_)) => {
+                return Err(ArgumentsError::Validation(format!(
+                    "--{} and --{} cannot be given at the same time.",
+                    EVENTS_FLAG, EVENTS_FD_FLAG
+                )))
+            }
+            (Some(path), None) => Some(EventStream::File(PathBuf::from(path))),
+            (None, Some(fd)) => Some(EventStream::FileDescriptor(fd)),
+            (None, None) => None,
+        };
+
         let input_file: Option<PathBuf> = matches.opt_str(INPUT_FILE_FLAG).and_then(
             #[no_coverage]
             |x| x.parse::<PathBuf>().ok(),
@@ -269,6 +579,35 @@ impl Arguments {
                 );
                 FuzzerCommand::MinifyInput { input_file }
             }
+            COMMAND_MINIFY_CORPUS => FuzzerCommand::MinifyCorpus,
+            COMMAND_MERGE => {
+                let corpora = matches
+                    .opt_strs(IN_CORPUS_FLAG)
+                    .into_iter()
+                    .map(PathBuf::from)
+                    .collect::<Vec<_>>();
+                if corpora.is_empty() {
+                    return Err(ArgumentsError::Validation(format!(
+                        "At least one input corpus must be provided when merging corpora. Use --{}",
+                        IN_CORPUS_FLAG
+                    )));
+                }
+                FuzzerCommand::MergeCorpora { corpora }
+            }
+            COMMAND_REPLAY => {
+                let folders = matches
+                    .opt_strs(IN_CORPUS_FLAG)
+                    .into_iter()
+                    .map(PathBuf::from)
+                    .collect::<Vec<_>>();
+                if folders.is_empty() {
+                    return Err(ArgumentsError::Validation(format!(
+                        "At least one folder must be provided when replaying test cases. Use --{}",
+                        IN_CORPUS_FLAG
+                    )));
+                }
+                FuzzerCommand::Replay { folders }
+            }
             _ => unreachable!(),
         };
 
@@ -289,12 +628,105 @@ impl Arguments {
                 |x| x.parse::<usize>().ok(),
             )
             .unwrap_or(usize::MAX);
+        let stop_after_no_progress = if let Some(limit) = matches.opt_str(NO_PROGRESS_FLAG) {
+            if let Some(limit) = NoProgressLimit::parse(&limit) {
+                Some(limit)
+            } else {
+                return Err(ArgumentsError::Validation(format!(
+                    "The value {} of --{} is not supported. It must be a number of iterations, such as 100000, or a duration in seconds, minutes, or hours, such as 30s, 5m, or 1h.",
+                    limit, NO_PROGRESS_FLAG
+                )));
+            }
+        } else {
+            None
+        };
+        let stop_at_coverage = if let Some(target) = matches.opt_str(STOP_AT_COVERAGE_FLAG) {
+            if let Some(target) = CoverageTarget::parse(&target) {
+                Some(target)
+            } else {
+                return Err(ArgumentsError::Validation(format!(
+                    "The value {} of --{} is not supported. It must be a percentage between 0 and 100, such as 80%, a source line, such as src/parser.rs:42, or the name of a function.",
+                    target, STOP_AT_COVERAGE_FLAG
+                )));
+            }
+        } else {
+            None
+        };
+        let timeout = if let Some(timeout) = matches.opt_str(TIMEOUT_FLAG) {
+            match timeout.parse::<u64>() {
+                Ok(milliseconds) if milliseconds > 0 => Some(Duration::from_millis(milliseconds)),
+                _ => {
+                    return Err(ArgumentsError::Validation(format!(
+                        "The value {} of --{} is not supported. It must be a positive number of milliseconds.",
+                        timeout, TIMEOUT_FLAG
+                    )));
+                }
+            }
+        } else {
+            None
+        };
         let stop_after_first_failure = matches.opt_present(STOP_AFTER_FIRST_FAILURE_FLAG);
+        let fork = matches.opt_present(FORK_FLAG);
+        let jobs = matches
+            .opt_str(JOBS_FLAG)
+            .and_then(
+                #[no_coverage]
+                |x| x.parse::<usize>().ok(),
+            )
+            .unwrap_or(1);
+        let trace_compares = matches.opt_present(TRACE_COMPARES_FLAG);
+        let seed = seed_from_matches(matches)?;
+        let backtrace_frames = matches
+            .opt_str(BACKTRACE_FRAMES_FLAG)
+            .and_then(
+                #[no_coverage]
+                |x| x.parse::<usize>().ok(),
+            )
+            .filter(
+                #[no_coverage]
+                |&n| n > 0,
+            );
+        let invalid_corpus = match matches.opt_str(INVALID_CORPUS_FLAG).as_deref() {
+            None => InvalidCorpusPolicy::default(),
+            Some(INVALID_CORPUS_IGNORE) => InvalidCorpusPolicy::Ignore,
+            Some(INVALID_CORPUS_WARN) => InvalidCorpusPolicy::Warn,
+            Some(INVALID_CORPUS_QUARANTINE) => InvalidCorpusPolicy::Quarantine,
+            Some(INVALID_CORPUS_FAIL) => InvalidCorpusPolicy::Fail,
+            Some(policy) => {
+                return Err(ArgumentsError::Validation(format!(
+                    r#"The value {p} of --{flag} is not supported. It can either be ‘{ignore}’, ‘{warn}’, ‘{quarantine}’, or ‘{fail}’."#,
+                    p = policy,
+                    flag = INVALID_CORPUS_FLAG,
+                    ignore = INVALID_CORPUS_IGNORE,
+                    warn = INVALID_CORPUS_WARN,
+                    quarantine = INVALID_CORPUS_QUARANTINE,
+                    fail = INVALID_CORPUS_FAIL,
+                )))
+            }
+        };
+        let corpus_sync_interval = matches.opt_str(CORPUS_SYNC_INTERVAL_FLAG).and_then(
+            #[no_coverage]
+            |x| x.parse::<u64>().ok(),
+        );
 
         let defaults = DefaultArguments::default();
         let max_input_cplx: f64 = max_input_cplx.unwrap_or(defaults.max_input_cplx as f64);
+        let corpus_sync_interval = match corpus_sync_interval {
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+            None => Some(defaults.corpus_sync_interval),
+        };
 
-        let corpus_in: Option<PathBuf> = if no_in_corpus.is_some() { None } else { corpus_in };
+        // the input corpora of the merge and replay commands are part of the command itself
+        let corpus_in: Option<PathBuf> = if no_in_corpus.is_some()
+            || matches!(
+                command,
+                FuzzerCommand::MergeCorpora { .. } | FuzzerCommand::Replay { .. }
+            ) {
+            None
+        } else {
+            corpus_in
+        };
         let corpus_out: Option<PathBuf> = if no_out_corpus.is_some() { None } else { corpus_out };
 
         let artifacts_folder: Option<PathBuf> = if no_artifacts.is_some() { None } else { artifacts_folder };
@@ -304,16 +736,48 @@ impl Arguments {
             command,
             maximum_duration,
             maximum_iterations,
+            stop_after_no_progress,
+            stop_at_coverage,
+            timeout,
             stop_after_first_failure,
+            fork,
+            jobs,
+            trace_compares,
+            seed,
+            backtrace_frames,
+            corpus_sync_interval,
             max_input_cplx,
             corpus_in,
             corpus_out,
             artifacts_folder,
             stats_folder,
+            resume,
+            invalid_corpus,
+            events,
         })
     }
 }
 
+/// The value of the `--seed` argument in the parsed result of [`options_parser()`], if any.
+///
+/// It is a validation error if the value is not a decimal `u64`.
+#[no_coverage]
+pub fn seed_from_matches(matches: &Matches) -> Result<Option<u64>, ArgumentsError> {
+    if let Some(seed) = matches.opt_str(SEED_FLAG) {
+        match seed.parse::<u64>() {
+            Ok(seed) => Ok(Some(seed)),
+            Err(_) => Err(ArgumentsError::Validation(format!(
+                "The value {} of --{} is not supported. It must be a decimal number between 0 and {}.",
+                seed,
+                SEED_FLAG,
+                u64::MAX
+            ))),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
 /// The “help” output of cargo-fuzzcheck
 #[no_coverage]
 pub fn help(parser: &Options) -> String {
@@ -358,9 +822,31 @@ cargo-fuzzcheck target1 {minify} --{input_file} "artifacts/crash.json"
     artifacts/crash.minified/ and name them {{complexity}}-{{hash}}.json. 
     For example, artifacts/crash.minified/4213--8cd7777109b57b8c.json
     is a minified input of complexity 42.13.
+
+cargo-fuzzcheck target1 {cmin} --{in_corpus} "fuzz/target1/corpus"
+    Using “target1”, find the smallest set of test cases of the input corpus
+    that keeps all the observations made by the sensor, choosing the simplest
+    test case for each of them. By default, the test cases are written to the
+    folder fuzz/target1/corpus.minified/.
+
+cargo-fuzzcheck target1 {merge} --{in_corpus} corpus_a --{in_corpus} corpus_b
+    Using “target1”, add to the folder fuzz/target1/corpus/ the test cases of
+    corpus_a and corpus_b that lead to new observations. The files that cannot
+    be decoded into valid test cases are listed at the end.
+
+cargo-fuzzcheck target1 {replay} --{in_corpus} fuzz/target1/artifacts
+    Using “target1”, run the test function on every test case of the folder
+    fuzz/target1/artifacts and of its subfolders, then print which ones failed.
+    The test function always runs in a child process, as with --{fork}, so
+    the command keeps going after a test case crashes.
 "#,
         fuzz = COMMAND_FUZZ,
         minify = COMMAND_MINIFY_INPUT,
+        cmin = COMMAND_MINIFY_CORPUS,
+        merge = COMMAND_MERGE,
+        replay = COMMAND_REPLAY,
+        fork = FORK_FLAG,
+        in_corpus = IN_CORPUS_FLAG,
         input_file = INPUT_FILE_FLAG,
         max_cplx = MAX_INPUT_CPLX_FLAG,
         out_corpus = OUT_CORPUS_FLAG,
@@ -422,3 +908,129 @@ impl From<Fail> for ArgumentsError {
         Self::Parsing(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_no_progress_limit() {
+        assert_eq!(NoProgressLimit::parse("1000"), Some(NoProgressLimit::Iterations(1000)));
+        assert_eq!(
+            NoProgressLimit::parse("30s"),
+            Some(NoProgressLimit::Duration(Duration::from_secs(30)))
+        );
+        assert_eq!(
+            NoProgressLimit::parse("5m"),
+            Some(NoProgressLimit::Duration(Duration::from_secs(5 * 60)))
+        );
+        assert_eq!(
+            NoProgressLimit::parse("1h"),
+            Some(NoProgressLimit::Duration(Duration::from_secs(60 * 60)))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_no_progress_limit() {
+        assert_eq!(NoProgressLimit::parse(""), None);
+        assert_eq!(NoProgressLimit::parse("s"), None);
+        assert_eq!(NoProgressLimit::parse("5d"), None);
+        assert_eq!(NoProgressLimit::parse("-5"), None);
+        assert_eq!(NoProgressLimit::parse("1.5m"), None);
+        assert_eq!(NoProgressLimit::parse("5 m"), None);
+        // the duration does not fit in a u64 number of seconds
+        assert_eq!(NoProgressLimit::parse(&format!("{}h", u64::MAX / 60)), None);
+    }
+
+    #[test]
+    fn test_display_no_progress_limit() {
+        for limit in ["1000", "30s", "5m", "1h"] {
+            let limit = NoProgressLimit::parse(limit).unwrap();
+            assert_eq!(NoProgressLimit::parse(&limit.to_string()), Some(limit));
+        }
+        assert_eq!(NoProgressLimit::parse("5m").unwrap().to_string(), "300s");
+    }
+
+    #[test]
+    fn test_parse_coverage_target() {
+        assert_eq!(CoverageTarget::parse("80%"), Some(CoverageTarget::Percentage(80.0)));
+        assert_eq!(CoverageTarget::parse("12.5%"), Some(CoverageTarget::Percentage(12.5)));
+        assert_eq!(CoverageTarget::parse("0%"), Some(CoverageTarget::Percentage(0.0)));
+        assert_eq!(CoverageTarget::parse("100%"), Some(CoverageTarget::Percentage(100.0)));
+        assert_eq!(
+            CoverageTarget::parse("src/parser.rs:42"),
+            Some(CoverageTarget::Line {
+                file: PathBuf::from("src/parser.rs"),
+                line: 42
+            })
+        );
+        assert_eq!(
+            CoverageTarget::parse("parse_expression"),
+            Some(CoverageTarget::Function("parse_expression".to_owned()))
+        );
+        // a path of a function is not a line, even though it contains colons
+        assert_eq!(
+            CoverageTarget::parse("parser::parse_expression"),
+            Some(CoverageTarget::Function("parser::parse_expression".to_owned()))
+        );
+        assert_eq!(
+            CoverageTarget::parse("src/parser.rs:x"),
+            Some(CoverageTarget::Function("src/parser.rs:x".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_coverage_target() {
+        assert_eq!(CoverageTarget::parse(""), None);
+        assert_eq!(CoverageTarget::parse("%"), None);
+        assert_eq!(CoverageTarget::parse("eighty%"), None);
+        assert_eq!(CoverageTarget::parse("-1%"), None);
+        assert_eq!(CoverageTarget::parse("100.5%"), None);
+        assert_eq!(CoverageTarget::parse(":42"), None);
+        assert_eq!(CoverageTarget::parse("parser::42"), None);
+    }
+
+    #[test]
+    fn test_display_coverage_target() {
+        for target in ["80%", "src/parser.rs:42", "parser::parse_expression"] {
+            assert_eq!(CoverageTarget::parse(target).unwrap().to_string(), target);
+        }
+    }
+
+    #[test]
+    fn test_invalid_timeout_is_a_validation_error() {
+        for timeout in ["0", "-1", "1.5", "10ms", ""] {
+            let matches = options_parser().parse(["--timeout-ms", timeout]).unwrap();
+            let result = Arguments::from_matches(&matches, false);
+            assert!(matches!(result, Err(ArgumentsError::Validation(_))), "{timeout}");
+        }
+        let matches = options_parser().parse(["--timeout-ms", "10"]).unwrap();
+        let arguments = Arguments::from_matches(&matches, false).unwrap();
+        assert_eq!(arguments.timeout, Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_invalid_seed_is_a_validation_error() {
+        for seed in ["0x10", "-1", "18446744073709551616", "seed"] {
+            let matches = options_parser().parse(["--seed", seed]).unwrap();
+            assert!(
+                matches!(seed_from_matches(&matches), Err(ArgumentsError::Validation(_))),
+                "{seed}"
+            );
+            let result = Arguments::from_matches(&matches, false);
+            assert!(matches!(result, Err(ArgumentsError::Validation(_))), "{seed}");
+        }
+        let matches = options_parser().parse(["--seed", "16"]).unwrap();
+        assert_eq!(seed_from_matches(&matches).unwrap(), Some(16));
+        assert_eq!(Arguments::from_matches(&matches, false).unwrap().seed, Some(16));
+    }
+
+    #[test]
+    fn test_missing_in_corpus_is_a_validation_error() {
+        for command in [COMMAND_MERGE, COMMAND_REPLAY] {
+            let matches = options_parser().parse(["--command", command]).unwrap();
+            let result = Arguments::from_matches(&matches, false);
+            assert!(matches!(result, Err(ArgumentsError::Validation(_))), "{command}");
+        }
+    }
+}
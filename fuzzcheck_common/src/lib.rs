@@ -2,14 +2,14 @@
 
 pub mod arg;
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Default, Debug)]
 pub struct FuzzerStats {
     pub total_number_of_runs: usize,
     pub number_of_runs_since_last_reset_time: usize,
     pub exec_per_s: usize,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum FuzzerEvent {
     Start,
     Stop,
@@ -21,5 +21,6 @@ pub enum FuzzerEvent {
     DidReadCorpus,
     CaughtSignal(i32),
     TestFailure,
+    Timeout,
     None,
 }
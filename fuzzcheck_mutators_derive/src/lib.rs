@@ -13,6 +13,7 @@ use synquote::{parser::TokenParser, token_builder::TokenBuilder};
 mod enums;
 mod single_variant;
 mod structs_and_enums;
+mod test_attribute;
 mod tuples;
 
 #[macro_use]
@@ -82,6 +83,13 @@ pub fn derive_default_mutator(item: proc_macro::TokenStream) -> proc_macro::Toke
     derive_default_mutator_(parser, settings).into()
 }
 
+#[proc_macro_attribute]
+pub fn test(attribute: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut tb = TokenBuilder::new();
+    test_attribute::make_test(&mut tb, attribute.into(), item.into());
+    tb.end().into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn make_single_variant_mutator(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -104,6 +104,19 @@ pub fn make_single_variant_mutator(tb: &mut TokenBuilder, enu: &Enum) {
             )
         }
     };
+    let other_pattern_match_binding_append = ident!("__proc_macro__other_binding__");
+    let other_item_pattern_match_bindings_to_tuple = |item_ident| {
+        if item_fields[item_ident].is_empty() {
+            ts!("()")
+        } else {
+            ts!("("
+                join_ts!(item_pattern_match_bindings[item_ident].iter(), binding,
+                    ident!(binding other_pattern_match_binding_append) ","
+                )
+                ")"
+            )
+        }
+    };
     let item_pattern_match_bindings_to_enum_item = |item: &EnumItem| {
         let fields = item.get_struct_data().map(|x| x.1).unwrap_or_default();
         ts!(
@@ -359,6 +372,66 @@ pub fn make_single_variant_mutator(tb: &mut TokenBuilder, enu: &Enum) {
             )" _ => unreachable!()
             }
         }
+
+        #[doc(hidden)]
+        #[no_coverage]
+        fn ordered_simplify(&self, value: &" enu.ident enum_generics_no_bounds ", cache: &Self::Cache, candidate: &mut dyn FnMut(" enu.ident enum_generics_no_bounds ") -> bool) -> bool {
+            match (self, value, cache) {"
+            join_ts!(&enu.items, item,
+                "(
+                    " EnumSingleVariant "::" item.ident "(m) ,
+                    " item.pattern_match(&enu.ident, Some(pattern_match_binding_append.clone())) ",
+                    " EnumSingleVariant "::" item.ident "(c) ,
+                ) => {
+                    m.ordered_simplify(" item_pattern_match_bindings_to_tuple(&item.ident, true) ", c, &mut |"
+                        if item_fields[&item.ident].is_empty() { "_" } else { "v" }
+                    "| candidate(" item_pattern_match_bindings_to_enum_item(item) "))
+                }"
+            )" _ => unreachable!()
+            }
+        }
+
+        #[doc(hidden)]
+        #[no_coverage]
+        fn crossover_mutate(
+            &self,
+            value: &mut " enu.ident enum_generics_no_bounds ",
+            cache: &mut Self::Cache,
+            other: &" enu.ident enum_generics_no_bounds ",
+            other_cache: &Self::Cache,
+            max_cplx: f64,
+        ) -> " cm.Option "<(Self::UnmutateToken, f64)> {
+            match (self, value, cache, other, other_cache) {"
+            join_ts!(&enu.items, item,
+                "(
+                    " EnumSingleVariant "::" item.ident "(m) ,
+                    " item.pattern_match(&enu.ident, Some(pattern_match_binding_append.clone())) ",
+                    " EnumSingleVariant "::" item.ident "(c) ,
+                    " item.pattern_match(&enu.ident, Some(other_pattern_match_binding_append.clone())) ",
+                    " EnumSingleVariant "::" item.ident "(other_c) ,
+                ) => {
+                    m.crossover_mutate(" item_pattern_match_bindings_to_tuple(&item.ident, true) ", c, " other_item_pattern_match_bindings_to_tuple(&item.ident) ", other_c, max_cplx)
+                        .map(#[no_coverage] |(t, c)| (" EnumSingleVariant "::" item.ident "(t), c))
+                }"
+            )" _ => " cm.None ",
+            }
+        }
+
+        #[doc(hidden)]
+        #[no_coverage]
+        fn describe_mutation(&self, value: &" enu.ident enum_generics_no_bounds ", token: &Self::UnmutateToken) -> String {
+            match (self, value, token) {"
+            join_ts!(&enu.items, item,
+                "(
+                    " EnumSingleVariant "::" item.ident "(m) ,
+                    " item.pattern_match(&enu.ident, Some(pattern_match_binding_append.clone())) ",
+                    " EnumSingleVariant "::" item.ident "(t) ,
+                ) => {
+                    m.describe_mutation(" item_pattern_match_bindings_to_tuple(&item.ident, false) ", t)
+                }"
+            )" _ => unreachable!()
+            }
+        }
     }
     ");
 }
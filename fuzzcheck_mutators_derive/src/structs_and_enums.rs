@@ -327,6 +327,39 @@ Generated by a procedural macro of [`fuzzcheck`]",
             {
                 " InnerMutator_as_Mutator "::recursing_part::<___V, ___N>(&self.mutator, parent, value, &mut index.inner)
             }
+            #[doc(hidden)]
+            #[no_coverage]
+            fn ordered_simplify(&self, value: & " type_ident type_generics.removing_bounds_and_eq_type() ", cache: &Self::Cache, candidate: &mut dyn FnMut(" type_ident type_generics.removing_bounds_and_eq_type() ") -> bool) -> bool {
+                " InnerMutator_as_Mutator "::ordered_simplify(&self.mutator, value, &cache.inner, candidate)
+            }
+            #[doc(hidden)]
+            #[no_coverage]
+            fn crossover_mutate(
+                &self,
+                value: &mut " type_ident type_generics.removing_bounds_and_eq_type() ",
+                cache: &mut Self::Cache,
+                other: &" type_ident type_generics.removing_bounds_and_eq_type() ",
+                other_cache: &Self::Cache,
+                max_cplx: f64,
+            ) -> Option<(Self::UnmutateToken, f64)> {
+                if let " cm.Some "((t, c)) = " InnerMutator_as_Mutator "::crossover_mutate(
+                    &self.mutator,
+                    value,
+                    &mut cache.inner,
+                    other,
+                    &other_cache.inner,
+                    max_cplx,
+                ) {
+                    " cm.Some "((Self::UnmutateToken::new(t), c))
+                } else {"
+                    cm.None
+                "}
+            }
+            #[doc(hidden)]
+            #[no_coverage]
+            fn describe_mutation(&self, value: &" type_ident type_generics.removing_bounds_and_eq_type() ", token: &Self::UnmutateToken) -> String {
+                " InnerMutator_as_Mutator "::describe_mutation(&self.mutator, value, &token.inner)
+            }
         }"
         if settings.default {
             ts!("impl" type_generics.removing_eq_type() cm.DefaultMutator "for" type_ident type_generics.removing_bounds_and_eq_type() DefaultMutator_where_clause "{"
@@ -0,0 +1,277 @@
+use decent_synquote_alternative as synquote;
+use proc_macro2::{Literal, Spacing, TokenStream, TokenTree};
+
+use synquote::parser::*;
+use synquote::token_builder::*;
+
+/// The options given to the `#[fuzzcheck::test(..)]` attribute
+#[derive(Default)]
+struct TestSettings {
+    mutator: Option<TokenStream>,
+    serializer: Option<TokenStream>,
+    max_cplx: Option<TokenStream>,
+    duration: Option<TokenStream>,
+}
+
+impl TestSettings {
+    /// Parse a list of `option = expression` separated by commas
+    fn from(attribute: TokenStream) -> Result<Self, String> {
+        let mut settings = TestSettings::default();
+        let tokens = attribute.into_iter().collect::<Vec<_>>();
+        let mut i = 0;
+        while i < tokens.len() {
+            let name = match (&tokens[i], tokens.get(i + 1)) {
+                (TokenTree::Ident(name), Some(TokenTree::Punct(eq))) if eq.as_char() == '=' => name.to_string(),
+                _ => return Err("expected an option of the form `name = value`".to_owned()),
+            };
+            i += 2;
+            let start = i;
+            while i < tokens.len() && !is_option_separator(&tokens[i..]) {
+                i += 1;
+            }
+            if start == i {
+                return Err(format!("the option `{}` has no value", name));
+            }
+            let value = Some(tokens[start..i].iter().cloned().collect::<TokenStream>());
+            let option = match name.as_str() {
+                "mutator" => &mut settings.mutator,
+                "serializer" => &mut settings.serializer,
+                "max_cplx" => &mut settings.max_cplx,
+                "duration" => &mut settings.duration,
+                _ => {
+                    return Err(format!(
+                        "unknown option `{}`, the options are `mutator`, `serializer`, `max_cplx`, and `duration`",
+                        name
+                    ))
+                }
+            };
+            if option.is_some() {
+                return Err(format!("the option `{}` is given more than once", name));
+            }
+            *option = value;
+            // skip the comma
+            i += 1;
+        }
+        Ok(settings)
+    }
+}
+
+/// Whether the tokens start with the comma separating two options, i.e. a comma followed by `name =`.
+///
+/// A comma alone is not enough, since it may also be part of an expression, such as in `Vec::<u8, A>::new()`.
+fn is_option_separator(tokens: &[TokenTree]) -> bool {
+    match tokens {
+        [TokenTree::Punct(comma)] => comma.as_char() == ',',
+        // the spacing of `=` is `Joint` when it is the first character of `==` or `=>`
+        [TokenTree::Punct(comma), TokenTree::Ident(_), TokenTree::Punct(eq), ..] => {
+            comma.as_char() == ',' && eq.as_char() == '=' && eq.spacing() == Spacing::Alone
+        }
+        _ => false,
+    }
+}
+
+pub fn make_test(tb: &mut TokenBuilder, attribute: TokenStream, item: TokenStream) {
+    let settings = match TestSettings::from(attribute) {
+        Ok(settings) => settings,
+        Err(message) => {
+            let message = Literal::string(&format!("#[fuzzcheck::test] {}", message));
+            extend_ts!(tb, "compile_error!(" message ");");
+            return;
+        }
+    };
+    let function = if let Some(function) = TokenParser::new(item.clone()).eat_function() {
+        function
+    } else {
+        let message = Literal::string("The #[fuzzcheck::test] attribute can only be used on functions.");
+        extend_ts!(tb, "compile_error!(" message ");");
+        return;
+    };
+    let name = function.ident;
+    let name_literal = Literal::string(&name.to_string());
+
+    let mutator = if let Some(mutator) = settings.mutator {
+        ts!(".mutator(" mutator ")")
+    } else {
+        ts!(".default_mutator()")
+    };
+    let serializer = if let Some(serializer) = settings.serializer {
+        ts!(".serializer(" serializer ")")
+    } else {
+        ts!(".serde_serializer()")
+    };
+    let max_cplx = settings
+        .max_cplx
+        .map(|max_cplx| ts!(".maximum_complexity((" max_cplx ") as f64)"));
+    let duration = settings
+        .duration
+        .map(|duration| ts!(".stop_after_duration(::std::time::Duration::from_secs(" duration "))"));
+
+    extend_ts!(tb,
+        "#[test]
+        fn" name "() {"
+            item
+            "let builder = fuzzcheck::fuzz_test(" name ")" mutator serializer ";
+            let result = if fuzzcheck::builder::launched_by_cargo_fuzzcheck() {
+                builder
+                    .default_sensor_and_pool()
+                    .arguments_from_cargo_fuzzcheck()" max_cplx.clone() duration "
+                    .launch()
+            } else {
+                builder
+                    .corpus_regression_check(&fuzzcheck::builder::test_name(::std::module_path!()," name_literal "))" max_cplx "
+                    .launch()
+            };
+            assert!(!result.found_test_failure);
+        }"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::synquote::token_builder::TokenBuilder;
+    use proc_macro2::TokenStream;
+
+    use super::{is_option_separator, make_test, TestSettings};
+
+    fn tokens(s: &str) -> TokenStream {
+        s.parse().unwrap()
+    }
+
+    fn settings(s: &str) -> Result<TestSettings, String> {
+        TestSettings::from(tokens(s))
+    }
+
+    fn to_string(option: Option<TokenStream>) -> Option<String> {
+        option.map(|ts| ts.to_string())
+    }
+
+    fn generated_test(attribute: &str, item: &str) -> String {
+        let mut tb = TokenBuilder::new();
+        make_test(&mut tb, tokens(attribute), tokens(item));
+        tb.end().to_string()
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        let s = settings("").unwrap();
+        assert!(s.mutator.is_none() && s.serializer.is_none() && s.max_cplx.is_none() && s.duration.is_none());
+
+        let s = settings("mutator = VecMutator::<u8, A>::new(x, 0..=10), max_cplx = 1.0, duration = 2,").unwrap();
+        assert_eq!(
+            to_string(s.mutator),
+            Some(tokens("VecMutator::<u8, A>::new(x, 0..=10)").to_string())
+        );
+        assert_eq!(to_string(s.max_cplx), Some(tokens("1.0").to_string()));
+        assert_eq!(to_string(s.duration), Some(tokens("2").to_string()));
+        assert!(s.serializer.is_none());
+
+        // commas and `==` inside an expression do not separate options
+        let s = settings("serializer = make(a == b, c), max_cplx = if a == b { 1 } else { 2 }").unwrap();
+        assert_eq!(to_string(s.serializer), Some(tokens("make(a == b, c)").to_string()));
+        assert_eq!(
+            to_string(s.max_cplx),
+            Some(tokens("if a == b { 1 } else { 2 }").to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_settings() {
+        assert_eq!(
+            settings("mutator").err().unwrap(),
+            "expected an option of the form `name = value`"
+        );
+        assert_eq!(
+            settings("mutator = ").err().unwrap(),
+            "the option `mutator` has no value"
+        );
+        assert_eq!(
+            settings("mutator = , max_cplx = 1").err().unwrap(),
+            "the option `mutator` has no value"
+        );
+        assert_eq!(
+            settings("max_cplx = 1, max_cplx = 2").err().unwrap(),
+            "the option `max_cplx` is given more than once"
+        );
+        assert!(settings("seed = 1").err().unwrap().starts_with("unknown option `seed`"));
+    }
+
+    #[test]
+    fn test_is_option_separator() {
+        let separator = |s: &str| is_option_separator(&tokens(s).into_iter().collect::<Vec<_>>());
+        assert!(separator(","));
+        assert!(separator(", max_cplx = 1"));
+        assert!(!separator(""));
+        assert!(!separator(", b"));
+        assert!(!separator(", a == b"));
+        assert!(!separator(", a => b"));
+        assert!(!separator(". a = b"));
+        assert!(!separator("x, a = b"));
+    }
+
+    #[test]
+    fn test_generated_test() {
+        let test = generated_test("", "fn my_test(x: &u8) {}");
+        assert!(test.starts_with(&tokens("#[test] fn my_test()").to_string()));
+        assert!(test.contains(&tokens("fn my_test(x: &u8) {}").to_string()));
+        let expected = [
+            "let builder = fuzzcheck::fuzz_test(my_test).default_mutator().serde_serializer();",
+            "let result = if fuzzcheck::builder::launched_by_cargo_fuzzcheck() {
+                builder.default_sensor_and_pool().arguments_from_cargo_fuzzcheck().launch()
+            } else {
+                builder
+                    .corpus_regression_check(&fuzzcheck::builder::test_name(::std::module_path!(), \"my_test\"))
+                    .launch()
+            };",
+            "assert!(!result.found_test_failure);",
+        ];
+        for expected in expected {
+            assert!(
+                test.contains(&tokens(expected).to_string()),
+                "{}\ndoes not contain\n{}",
+                test,
+                expected
+            );
+        }
+
+        let test = generated_test(
+            "mutator = m(), serializer = s(), max_cplx = 4, duration = 5",
+            "fn t(x: &u8) {}",
+        );
+        let expected = [
+            "let builder = fuzzcheck::fuzz_test(t).mutator(m()).serializer(s());",
+            "let result = if fuzzcheck::builder::launched_by_cargo_fuzzcheck() {
+                builder.default_sensor_and_pool().arguments_from_cargo_fuzzcheck()
+                    .maximum_complexity((4) as f64)
+                    .stop_after_duration(::std::time::Duration::from_secs(5))
+                    .launch()
+            } else {
+                builder
+                    .corpus_regression_check(&fuzzcheck::builder::test_name(::std::module_path!(), \"t\"))
+                    .maximum_complexity((4) as f64)
+                    .launch()
+            };",
+        ];
+        for expected in expected {
+            assert!(
+                test.contains(&tokens(expected).to_string()),
+                "{}\ndoes not contain\n{}",
+                test,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_generated_errors() {
+        let test = generated_test("mutator", "fn t(x: &u8) {}");
+        assert_eq!(
+            test,
+            tokens("compile_error!(\"#[fuzzcheck::test] expected an option of the form `name = value`\");").to_string()
+        );
+        let test = generated_test("", "struct S;");
+        assert_eq!(
+            test,
+            tokens("compile_error!(\"The #[fuzzcheck::test] attribute can only be used on functions.\");").to_string()
+        );
+    }
+}
@@ -639,6 +639,66 @@ fn impl_mutator_trait(tb: &mut TokenBuilder, nbr_elements: usize) {
             )"
             }
         }
+        #[doc(hidden)]
+        #[no_coverage]
+        fn ordered_simplify<'a>(&self, value:" tuple_ref ", cache: &'a Self::Cache, candidate: &mut dyn FnMut(T) -> bool) -> bool {"
+            join_ts!(0..nbr_elements, i,
+                "if self." mutator_i(i) ".ordered_simplify(value." i ", &cache." ti(i) ", &mut #[no_coverage] |simpler| {
+                    candidate(T::new(("
+                        join_ts!(0..nbr_elements, j,
+                            if i == j { ts!("simpler") } else { ts!("value." j ".clone()") }
+                        , separator: ",")
+                    ")))
+                }) {
+                    return true;
+                }"
+            )
+            "false
+        }
+        #[doc(hidden)]
+        #[no_coverage]
+        fn crossover_mutate<'a>(
+            &self,
+            value: " tuple_mut ",
+            cache: &'a mut Self::Cache,
+            other: " tuple_ref ",
+            other_cache: &'a Self::Cache,
+            max_cplx: f64,
+        ) -> " cm.Option "<(Self::UnmutateToken, f64)> {
+            let current_cplx = " SelfAsTupleMutator "::complexity(self, " TupleNAsRefTypes "::get_ref_from_mut(&value), cache);
+            let start = self.rng.usize(.." nbr_elements ");
+            for offset in 0.." nbr_elements " {
+                match (start + offset) % " nbr_elements " {"
+                join_ts!(0..nbr_elements, i,
+                    i "=> {
+                        let old_field_cplx = self." mutator_i(i) ".complexity(value." i ", &cache." ti(i) ");
+                        let max_field_cplx = max_cplx - current_cplx + old_field_cplx;
+                        if let " cm.Some "((token, new_field_cplx)) = self." mutator_i(i) "
+                            .crossover_mutate(value." i ", &mut cache." ti(i) ", other." i ", &other_cache." ti(i) ", max_field_cplx)
+                        {
+                            return " cm.Some "((Self::UnmutateToken {
+                                " ti(i) ": " cm.Some "(token),
+                                ..Self::UnmutateToken::default()
+                            }, current_cplx - old_field_cplx + new_field_cplx));
+                        }
+                    }"
+                )
+                "_ => unreachable!()
+                }
+            }
+            " cm.None "
+        }
+        #[doc(hidden)]
+        #[no_coverage]
+        fn describe_mutation<'a>(&self, value: " tuple_ref ", token: &Self::UnmutateToken) -> String {
+            let mut descriptions = vec![];"
+            join_ts!(0..nbr_elements, i,
+                "if let " cm.Some "(subtoken) = &token." ti(i) "{
+                    descriptions.push(format!(" Literal::string(".{}: {}") ", " i ", self." mutator_i(i) ".describe_mutation(value." i ", subtoken)));
+                }"
+            )
+            "descriptions.join(" Literal::string(", ") ")
+        }
     }
     "
     )